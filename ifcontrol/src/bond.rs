@@ -0,0 +1,184 @@
+use eui48::MacAddress;
+use impls;
+use {IfError, Iface};
+
+/// How the bond distributes traffic among its slaves
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BondMode {
+    /// Sequentially through each slave (the kernel default)
+    BalanceRr,
+    /// Through the single active slave, others take over on its failure
+    ActiveBackup,
+    /// By the hash of the packet, see `XmitHashPolicy`
+    BalanceXor,
+    /// Through every slave
+    Broadcast,
+    /// IEEE 802.3ad dynamic link aggregation
+    Lacp,
+    /// Outgoing traffic is balanced by the load of the slaves
+    BalanceTlb,
+    /// Same as `BalanceTlb`, but incoming IPv4 traffic is balanced too
+    BalanceAlb,
+    /// Mode, unknown to this crate
+    Other(u8),
+}
+
+/// Packet fields hashed to pick the slave in `BalanceXor` and `Lacp` modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XmitHashPolicy {
+    /// MAC addresses (the kernel default)
+    Layer2,
+    /// IP addresses and ports
+    Layer34,
+    /// MAC and IP addresses
+    Layer23,
+    /// Same as `Layer23`, but of the inner headers of the tunneled packets
+    Encap23,
+    /// Same as `Layer34`, but of the inner headers of the tunneled packets
+    Encap34,
+    /// Policy, unknown to this crate, e.g. vlan+srcmac of the newer kernels
+    Other(u8),
+}
+
+/// Link aggregation of several interfaces (`ip link add <name> type bond mode <mode>`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bond {
+    pub name: String,
+    pub mode: BondMode,
+    /// Interval of the MII link monitoring in milliseconds, 0 disables it
+    pub miimon: u32,
+    pub xmit_hash_policy: XmitHashPolicy,
+    /// Slave preferred to be the active one in `ActiveBackup` mode. It has to be
+    /// enslaved before, so it is only applied to the existing bond by the kernel,
+    /// see `set_primary`
+    pub primary: Option<String>,
+}
+
+impl Bond {
+    pub fn new(name: &str, mode: BondMode) -> Bond {
+        Bond {
+            name: name.to_owned(),
+            mode,
+            miimon: 0,
+            xmit_hash_policy: XmitHashPolicy::Layer2,
+            primary: None,
+        }
+    }
+
+    /// Settings of the existing bond. `IfError::NotFound` if it is not a bond
+    pub fn find_by_name(ifname: &str) -> Result<Bond, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::get_bond(&mut nl, ifname)
+    }
+
+    /// Creates the interface, which is down. It is removed by `Iface::delete`
+    pub fn create(&self) -> Result<Iface, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::create_bond(&mut nl, self)?;
+        Iface::find_by_name(&self.name)
+    }
+
+    /// Adds the interface to the bond (`ip link set <slave> master <bond>`).
+    /// The slave is brought down first, as the kernel requires
+    pub fn enslave(&self, slave: &str) -> Result<(), IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::set_admin_down(&ctl_fd, slave)?;
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::set_link_master(&mut nl, slave, Some(&self.name))
+    }
+
+    /// Makes the enslaved interface the preferred active one in `ActiveBackup` mode
+    /// (`ip link set <bond> type bond primary <slave>`)
+    pub fn set_primary(&mut self, slave: &str) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::set_bond_primary(&mut nl, &self.name, slave)?;
+        self.primary = Some(slave.to_owned());
+        Ok(())
+    }
+
+    /// Removes the interface from the bond (`ip link set <slave> nomaster`)
+    pub fn release(&self, slave: &str) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::set_link_master(&mut nl, slave, None)
+    }
+
+    /// State of the enslaved interfaces
+    pub fn slaves(&self) -> Result<Vec<BondSlave>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::list_bond_slaves(&mut nl, &self.name)
+    }
+
+    /// Slave currently carrying the traffic in `ActiveBackup` mode
+    pub fn active_slave(&self) -> Result<Option<String>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::get_bond_active_slave(&mut nl, &self.name)
+    }
+}
+
+/// Link state of the slave as seen by the MII monitoring
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MiiStatus {
+    Up,
+    /// Has just gone down, waiting for `downdelay`
+    Fail,
+    Down,
+    /// Is going back up, waiting for `updelay`
+    Back,
+    /// Status, unknown to this crate
+    Other(u8),
+}
+
+/// Interface enslaved to the bond (`IFLA_BOND_SLAVE_*`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct BondSlave {
+    pub name: String,
+    /// Carries the traffic rather than being a backup
+    pub active: bool,
+    pub mii_status: MiiStatus,
+    pub link_failure_count: u32,
+    /// Address of the interface before it was enslaved, as the bond may replace it
+    pub perm_hw_addr: Option<MacAddress>,
+    pub queue_id: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tests::skip_unsupported;
+    use Veth;
+
+    #[test]
+    fn test_bond() {
+        let member = Veth::new("ifctlbond1", "ifctlbond2")
+            .create()
+            .expect("create veth");
+
+        let mut bond = Bond::new("ifctlbond0", BondMode::ActiveBackup);
+        bond.miimon = 100;
+        bond.xmit_hash_policy = XmitHashPolicy::Layer34;
+        let created = bond.create();
+        if !skip_unsupported("bond", &created) {
+            let iface = created.expect("create bond");
+            assert_eq!(Bond::find_by_name("ifctlbond0").unwrap(), bond);
+            assert!(Bond::find_by_name("ifctlbond1").is_err());
+
+            bond.enslave("ifctlbond1").expect("enslave");
+            let slaves = bond.slaves().unwrap();
+            assert_eq!(slaves.len(), 1);
+            assert_eq!(slaves[0].name, "ifctlbond1");
+            assert_eq!(slaves[0].perm_hw_addr, member.hw_addr());
+            assert_eq!(member.master().unwrap(), Some("ifctlbond0".to_owned()));
+            assert_eq!(iface.slaves().unwrap(), vec!["ifctlbond1".to_owned()]);
+
+            bond.set_primary("ifctlbond1").expect("set primary");
+            assert_eq!(Bond::find_by_name("ifctlbond0").unwrap(), bond);
+
+            bond.release("ifctlbond1").expect("release");
+            assert!(bond.slaves().unwrap().is_empty());
+            assert_eq!(member.master().unwrap(), None);
+            iface.delete().expect("delete bond");
+        }
+
+        member.delete().expect("delete veth");
+    }
+}
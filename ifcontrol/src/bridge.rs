@@ -0,0 +1,329 @@
+#[cfg(target_os = "linux")]
+use eui48::MacAddress;
+use impls;
+#[cfg(target_os = "linux")]
+use std::net::IpAddr;
+#[cfg(target_os = "linux")]
+use std::time::Duration;
+#[cfg(target_os = "linux")]
+use FdbEntry;
+use {IfError, Iface, Link};
+
+/// Software bridge
+#[derive(Debug, Clone)]
+pub struct Bridge {
+    name: String,
+}
+
+impl Bridge {
+    /// Creates the bridge, which is down
+    pub fn create(name: &str) -> Result<Bridge, IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::create_bridge(&ctl_fd, name)?;
+        Ok(Bridge {
+            name: name.to_owned(),
+        })
+    }
+
+    /// `IfError::NotFound` if the interface is not a bridge
+    pub fn find_by_name(name: &str) -> Result<Bridge, IfError> {
+        match *Iface::find_by_name(name)?.link() {
+            Link::Bridge => Ok(Bridge {
+                name: name.to_owned(),
+            }),
+            _ => Err(IfError::NotFound),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn delete(self) -> Result<(), IfError> {
+        // linux refuses to remove the bridge, which is up, even without carrier
+        let ctl_fd = impls::new_control_socket()?;
+        impls::set_admin_down(&ctl_fd, &self.name)?;
+        impls::remove_bridge(&ctl_fd, &self.name)
+    }
+
+    pub fn add_port(&self, port: &str) -> Result<(), IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::add_iface_to_bridge(&ctl_fd, &self.name, port)
+    }
+
+    pub fn remove_port(&self, port: &str) -> Result<(), IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::remove_iface_from_bridge(&ctl_fd, &self.name, port)
+    }
+}
+
+/// Bridge-wide settings (`IFLA_BR_*`)
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BridgeSettings {
+    /// Spanning tree protocol
+    pub stp: bool,
+    /// Lifetime of the learned FDB entries
+    pub ageing_time: Duration,
+    /// Time, spent by the ports in the listening and learning STP states
+    pub forward_delay: Duration,
+    /// Forward only the frames of the VLANs, configured on the ports
+    pub vlan_filtering: bool,
+}
+
+/// VLAN of the bridge port or the bridge itself
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BridgeVlan {
+    pub vid: u16,
+    /// Untagged ingress frames are assigned to this VLAN
+    pub pvid: bool,
+    /// Egress frames of this VLAN are sent untagged
+    pub untagged: bool,
+}
+
+#[cfg(target_os = "linux")]
+impl BridgeVlan {
+    /// Tagged VLAN
+    pub fn new(vid: u16) -> BridgeVlan {
+        BridgeVlan {
+            vid,
+            pvid: false,
+            untagged: false,
+        }
+    }
+}
+
+/// Multicast group membership of the bridge port (`bridge mdb add ...`)
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MdbEntry {
+    pub bridge: String,
+    pub port: String,
+    pub group: IpAddr,
+    pub vid: Option<u16>,
+    /// Temporary entries expire, unless they are refreshed by IGMP/MLD reports
+    pub permanent: bool,
+}
+
+#[cfg(target_os = "linux")]
+impl MdbEntry {
+    /// Permanent entry
+    pub fn new(bridge: &str, port: &str, group: IpAddr) -> MdbEntry {
+        MdbEntry {
+            bridge: bridge.to_owned(),
+            port: port.to_owned(),
+            group,
+            vid: None,
+            permanent: true,
+        }
+    }
+
+    /// Both the bridge and the port must be up
+    pub fn add(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::add_mdb(&mut nl, self)
+    }
+
+    pub fn delete(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::del_mdb(&mut nl, self)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Bridge {
+    /// Names of the enslaved interfaces
+    pub fn ports(&self) -> Result<Vec<String>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::list_bridge_ports(&mut nl, &self.name)
+    }
+
+    pub fn settings(&self) -> Result<BridgeSettings, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::get_bridge_settings(&mut nl, &self.name)
+    }
+
+    pub fn set_stp(&self, enable: bool) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::set_bridge_stp(&mut nl, &self.name, enable)
+    }
+
+    /// Precision is 1/100 of a second
+    pub fn set_ageing_time(&self, time: Duration) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::set_bridge_ageing_time(&mut nl, &self.name, time)
+    }
+
+    /// Precision is 1/100 of a second. With STP it should be in 2..30 seconds range
+    pub fn set_forward_delay(&self, delay: Duration) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::set_bridge_forward_delay(&mut nl, &self.name, delay)
+    }
+
+    pub fn set_vlan_filtering(&self, enable: bool) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::set_bridge_vlan_filtering(&mut nl, &self.name, enable)
+    }
+
+    /// Adds VLAN to the `port`, which is either the port or the bridge itself.
+    /// Existing VLAN with the same id is updated
+    pub fn add_vlan(&self, port: &str, vlan: &BridgeVlan) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::add_bridge_vlan(&mut nl, &self.name, port, vlan)
+    }
+
+    pub fn del_vlan(&self, port: &str, vid: u16) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::del_bridge_vlan(&mut nl, &self.name, port, vid)
+    }
+
+    /// VLANs of the `port`, which is either the port or the bridge itself.
+    /// `IfError::NotFound` if the interface is neither of them
+    pub fn vlans(&self, port: &str) -> Result<Vec<BridgeVlan>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        if port != self.name
+            && !impls::list_bridge_ports(&mut nl, &self.name)?
+                .iter()
+                .any(|p| p == port)
+        {
+            return Err(IfError::NotFound);
+        }
+        impls::list_bridge_vlans(&mut nl, port)
+    }
+
+    /// Static forwarding entry of the `port` in the bridge database. It is managed with
+    /// `FdbEntry::add` and `FdbEntry::delete`
+    pub fn fdb_entry(&self, port: &str, lladdr: MacAddress) -> FdbEntry {
+        FdbEntry::new_master(port, lladdr)
+    }
+
+    /// Forwarding database entries of the bridge and its ports
+    pub fn fdb_entries(&self) -> Result<Vec<FdbEntry>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        let mut ifaces = impls::list_bridge_ports(&mut nl, &self.name)?;
+        ifaces.push(self.name.clone());
+        Ok(impls::list_fdb(&mut nl)?
+            .into_iter()
+            .filter(|e| ifaces.contains(&e.iface))
+            .collect())
+    }
+
+    pub fn mdb_entries(&self) -> Result<Vec<MdbEntry>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::list_mdb(&mut nl, &self.name)
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use nix::errno::Errno;
+    use std::io;
+    use Veth;
+
+    fn check_vlans(bridge: &Bridge) {
+        let access = BridgeVlan {
+            vid: 18,
+            pvid: true,
+            untagged: true,
+        };
+        bridge.add_vlan("ifctlbrp0", &access).expect("add vlan");
+        bridge
+            .add_vlan("ifctlbrp0", &BridgeVlan::new(19))
+            .expect("add vlan");
+        bridge
+            .add_vlan("ifctlbr0", &BridgeVlan::new(19))
+            .expect("add bridge vlan");
+        let vlans = bridge.vlans("ifctlbrp0").unwrap();
+        assert!(vlans.contains(&access));
+        assert!(vlans.contains(&BridgeVlan::new(19)));
+        assert!(bridge
+            .vlans("ifctlbr0")
+            .unwrap()
+            .contains(&BridgeVlan::new(19)));
+        bridge.del_vlan("ifctlbrp0", 19).expect("delete vlan");
+        assert!(!bridge
+            .vlans("ifctlbrp0")
+            .unwrap()
+            .contains(&BridgeVlan::new(19)));
+    }
+
+    #[test]
+    fn test_bridge() {
+        let bridge = Bridge::create("ifctlbr0").expect("create bridge");
+        let port = Veth::new("ifctlbrp0", "ifctlbrp1")
+            .create()
+            .expect("create veth");
+        bridge.add_port("ifctlbrp0").expect("add port");
+        assert_eq!(bridge.ports().unwrap(), vec!["ifctlbrp0".to_owned()]);
+        match bridge.vlans("ifctlbrp1") {
+            Err(IfError::NotFound) => {}
+            r => panic!("vlans of the foreign interface: {:?}", r),
+        }
+        assert!(Bridge::find_by_name("ifctlbrp0").is_err());
+
+        bridge.set_stp(true).expect("enable stp");
+        bridge
+            .set_ageing_time(Duration::from_secs(120))
+            .expect("set ageing time");
+        bridge
+            .set_forward_delay(Duration::from_millis(2500))
+            .expect("set forward delay");
+        match bridge.set_ageing_time(Duration::from_secs(u64::from(u32::MAX))) {
+            Err(IfError::Io(ref e)) if e.kind() == io::ErrorKind::InvalidInput => {}
+            r => panic!("out of range ageing time is accepted: {:?}", r),
+        }
+        let settings = Bridge::find_by_name("ifctlbr0")
+            .unwrap()
+            .settings()
+            .unwrap();
+        assert_eq!(
+            settings,
+            BridgeSettings {
+                stp: true,
+                ageing_time: Duration::from_secs(120),
+                forward_delay: Duration::from_millis(2500),
+                vlan_filtering: false,
+            }
+        );
+        bridge.set_stp(false).expect("disable stp");
+        assert!(!bridge.settings().unwrap().stp);
+
+        // kernel may be built without vlan filtering support
+        let vid = match bridge.set_vlan_filtering(true) {
+            Err(IfError::Nix(::nix::Error::Sys(Errno::EOPNOTSUPP))) => None,
+            r => {
+                r.expect("enable vlan filtering");
+                assert!(bridge.settings().unwrap().vlan_filtering);
+                check_vlans(&bridge);
+                Some(18)
+            }
+        };
+
+        let mut fdb = bridge.fdb_entry(
+            "ifctlbrp0",
+            MacAddress::parse_str("02:00:00:00:18:01").unwrap(),
+        );
+        fdb.vlan = vid;
+        fdb.add().expect("add fdb entry");
+        assert!(bridge.fdb_entries().unwrap().contains(&fdb));
+        fdb.delete().expect("delete fdb entry");
+        assert!(!bridge.fdb_entries().unwrap().contains(&fdb));
+
+        Iface::up_by_name("ifctlbr0").unwrap();
+        Iface::up_by_name("ifctlbrp1").unwrap();
+        port.up().unwrap();
+        let mut mdb = MdbEntry::new("ifctlbr0", "ifctlbrp0", "239.1.1.18".parse().unwrap());
+        mdb.vid = vid;
+        mdb.add().expect("add mdb entry");
+        assert_eq!(bridge.mdb_entries().unwrap(), vec![mdb.clone()]);
+        mdb.delete().expect("delete mdb entry");
+        assert!(bridge.mdb_entries().unwrap().is_empty());
+
+        bridge.remove_port("ifctlbrp0").expect("remove port");
+        assert!(bridge.ports().unwrap().is_empty());
+        port.delete().expect("delete veth");
+        bridge.delete().expect("delete bridge");
+    }
+}
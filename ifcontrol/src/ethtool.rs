@@ -0,0 +1,244 @@
+use impls;
+use std::collections::HashMap;
+use {IfError, Iface};
+
+/// Generic receive offload (`ethtool -K <iface> gro`)
+pub const FEATURE_GRO: &str = "rx-gro";
+/// Generic segmentation offload (`gso`)
+pub const FEATURE_GSO: &str = "tx-generic-segmentation";
+/// TCP segmentation offload for IPv4 (`tso`)
+pub const FEATURE_TSO: &str = "tx-tcp-segmentation";
+/// Protocol-independent TX checksumming (`tx`), which virtual interfaces use
+pub const FEATURE_TX_CHECKSUM: &str = "tx-checksum-ip-generic";
+/// RX checksumming (`rx`)
+pub const FEATURE_RX_CHECKSUM: &str = "rx-checksum";
+/// Scatter-gather (`sg`)
+pub const FEATURE_SG: &str = "tx-scatter-gather";
+/// RX VLAN tag stripping (`rxvlan`)
+pub const FEATURE_RX_VLAN: &str = "rx-vlan-hw-parse";
+
+/// State of the netdev feature (offload), as reported by `ETHTOOL_GFEATURES`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureState {
+    pub active: bool,
+    /// Requested by the user. It may be inactive, if it depends on another disabled feature
+    pub requested: bool,
+    /// Can't be changed by the user
+    pub fixed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Duplex {
+    Half,
+    Full,
+    Unknown,
+}
+
+/// Physical connector of the interface
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Port {
+    TwistedPair,
+    Aui,
+    Bnc,
+    Mii,
+    Fibre,
+    /// Direct attach copper cable
+    DirectAttach,
+    None,
+    Other,
+}
+
+/// Speed, duplex and autonegotiation of the link (`ETHTOOL_GLINKSETTINGS`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkSettings {
+    /// In Mb/s, `None` if the link is down or the speed is unknown
+    pub speed: Option<u32>,
+    pub duplex: Duplex,
+    pub port: Port,
+    pub autoneg: bool,
+}
+
+/// Sizes of the NIC descriptor rings (`ethtool -g <iface>`). Maximums are read-only
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RingParams {
+    pub rx_max: u32,
+    pub rx_mini_max: u32,
+    pub rx_jumbo_max: u32,
+    pub tx_max: u32,
+    pub rx: u32,
+    pub rx_mini: u32,
+    pub rx_jumbo: u32,
+    pub tx: u32,
+}
+
+/// Numbers of the queues (`ethtool -l <iface>`). Maximums are read-only
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Channels {
+    pub max_rx: u32,
+    pub max_tx: u32,
+    pub max_other: u32,
+    pub max_combined: u32,
+    /// RX-only queues
+    pub rx: u32,
+    /// TX-only queues
+    pub tx: u32,
+    /// Queues for link interrupts, SR-IOV coordination etc.
+    pub other: u32,
+    /// Queues, which serve both RX and TX
+    pub combined: u32,
+}
+
+impl Iface {
+    /// Netdev features by their names, e.g. `FEATURE_GRO` (`ethtool -k <iface>`)
+    pub fn features(&self) -> Result<HashMap<String, FeatureState>, IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::get_features(&ctl_fd, &self.ifname)
+    }
+
+    /// Enables or disables the features by their names (`ethtool -K <iface> ...`).
+    /// Unknown names are `io::ErrorKind::InvalidInput`. It's an error too, if the driver
+    /// applies only some of the changes
+    pub fn set_features(&self, features: &[(&str, bool)]) -> Result<(), IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::set_features(&ctl_fd, &self.ifname, features)
+    }
+
+    /// Driver-specific counters by their names, e.g. per-queue ones (`ethtool -S <iface>`)
+    pub fn driver_stats(&self) -> Result<HashMap<String, u64>, IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::get_driver_stats(&ctl_fd, &self.ifname)
+    }
+
+    pub fn link_settings(&self) -> Result<LinkSettings, IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::get_link_settings(&ctl_fd, &self.ifname)
+    }
+
+    /// Forces speed and duplex or enables autonegotiation (`ethtool -s <iface> ...`).
+    /// The advertised link modes are left as is
+    pub fn set_link_settings(&self, settings: &LinkSettings) -> Result<(), IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::set_link_settings(&ctl_fd, &self.ifname, settings)
+    }
+
+    pub fn ring_params(&self) -> Result<RingParams, IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::get_ring_params(&ctl_fd, &self.ifname)
+    }
+
+    /// Resizes the rings (`ethtool -G <iface> ...`)
+    pub fn set_ring_params(&self, params: &RingParams) -> Result<(), IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::set_ring_params(&ctl_fd, &self.ifname, params)
+    }
+
+    pub fn channels(&self) -> Result<Channels, IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::get_channels(&ctl_fd, &self.ifname)
+    }
+
+    /// Changes the numbers of the queues (`ethtool -L <iface> ...`)
+    pub fn set_channels(&self, channels: &Channels) -> Result<(), IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::set_channels(&ctl_fd, &self.ifname, channels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nix::errno::Errno;
+    use std::io;
+    use Veth;
+
+    #[test]
+    fn test_features() {
+        let iface = Veth::new("ifctleth0", "ifctleth1")
+            .create()
+            .expect("create veth");
+
+        let features = iface.features().expect("get features");
+        for name in &[FEATURE_GRO, FEATURE_TSO, FEATURE_TX_CHECKSUM, FEATURE_SG] {
+            assert!(!features[*name].fixed, "{} is fixed", name);
+        }
+
+        let enabled = features[FEATURE_GRO].active;
+        iface
+            .set_features(&[(FEATURE_GRO, !enabled), (FEATURE_TX_CHECKSUM, false)])
+            .expect("toggle features");
+        let features = iface.features().unwrap();
+        assert_eq!(features[FEATURE_GRO].active, !enabled);
+        assert!(!features[FEATURE_TX_CHECKSUM].requested);
+
+        match iface.set_features(&[("no-such-feature", true)]) {
+            Err(IfError::Io(ref e)) if e.kind() == io::ErrorKind::InvalidInput => {}
+            r => panic!("unexpected result {:?}", r),
+        }
+        let (fixed, state) = features.iter().find(|(_, s)| s.fixed).unwrap();
+        match iface.set_features(&[(fixed, !state.active)]) {
+            Err(IfError::Nix(::nix::Error::Sys(Errno::EOPNOTSUPP))) => {}
+            r => panic!("fixed feature is changed: {:?}", r),
+        }
+
+        iface.delete().expect("delete veth");
+    }
+
+    #[test]
+    fn test_driver_stats() {
+        let mut veth = Veth::new("ifctleth6", "ifctleth7");
+        veth.queues = Some(2);
+        let iface = veth.create().expect("create veth");
+        let peer = Iface::find_by_name("ifctleth7").unwrap();
+
+        let stats = iface.driver_stats().expect("get driver stats");
+        assert_eq!(stats["peer_ifindex"], u64::from(peer.index()));
+        assert!(stats.contains_key("rx_queue_1_xdp_packets"));
+
+        iface.delete().expect("delete veth");
+    }
+
+    #[test]
+    fn test_link_settings() {
+        let iface = Veth::new("ifctleth2", "ifctleth3")
+            .create()
+            .expect("create veth");
+
+        // veth pretends to be a 10G twisted pair link
+        let settings = iface.link_settings().expect("get link settings");
+        assert_eq!(settings.speed, Some(10000));
+        assert_eq!(settings.duplex, Duplex::Full);
+        assert_eq!(settings.port, Port::TwistedPair);
+        assert!(!settings.autoneg);
+
+        match iface.set_link_settings(&settings) {
+            Err(IfError::Nix(::nix::Error::Sys(Errno::EOPNOTSUPP))) => {}
+            r => panic!("unexpected result {:?}", r),
+        }
+
+        iface.delete().expect("delete veth");
+    }
+
+    #[test]
+    fn test_ring_params_and_channels() {
+        let mut veth = Veth::new("ifctleth4", "ifctleth5");
+        veth.queues = Some(4);
+        let iface = veth.create().expect("create veth");
+
+        let mut channels = iface.channels().expect("get channels");
+        assert_eq!(channels.max_rx, 4);
+        channels.rx = 2;
+        iface.set_channels(&channels).expect("set channels");
+        assert_eq!(iface.channels().unwrap().rx, 2);
+
+        match iface.ring_params() {
+            // veth has no rings
+            Err(IfError::Nix(::nix::Error::Sys(Errno::EOPNOTSUPP))) => {}
+            r => {
+                let params = r.expect("get ring params");
+                iface.set_ring_params(&params).expect("set ring params");
+            }
+        }
+
+        iface.delete().expect("delete veth");
+    }
+}
@@ -0,0 +1,525 @@
+use eui48::MacAddress;
+use futures::{Async, Poll, Stream};
+use ifstructs::{
+    IfFlags, RTMGRP_IPV4_IFADDR, RTMGRP_IPV4_ROUTE, RTMGRP_IPV6_IFADDR, RTMGRP_IPV6_ROUTE,
+    RTMGRP_LINK, RTM_DELADDR, RTM_DELLINK, RTM_DELROUTE, RTM_NEWLINK,
+};
+use impls;
+use ipnetwork::IpNetwork;
+use libc;
+use mio;
+use nix;
+use nix::errno::Errno;
+use std::collections::{HashMap, VecDeque};
+use tokio_reactor::PollEvented;
+use {IfError, Iface, Route};
+
+/// Change of the network interface, received from `RTNLGRP_LINK`
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkEvent {
+    Added {
+        ifname: String,
+    },
+    Removed {
+        ifname: String,
+    },
+    Up {
+        ifname: String,
+    },
+    Down {
+        ifname: String,
+    },
+    CarrierUp {
+        ifname: String,
+    },
+    CarrierDown {
+        ifname: String,
+    },
+    Renamed {
+        old: String,
+        new: String,
+    },
+    MtuChanged {
+        ifname: String,
+        mtu: u32,
+    },
+    HwAddrChanged {
+        ifname: String,
+        hw_addr: Option<MacAddress>,
+    },
+}
+
+impl LinkEvent {
+    /// Name of the interface. The new one for `Renamed`
+    pub fn ifname(&self) -> &str {
+        match *self {
+            LinkEvent::Added { ref ifname }
+            | LinkEvent::Removed { ref ifname }
+            | LinkEvent::Up { ref ifname }
+            | LinkEvent::Down { ref ifname }
+            | LinkEvent::CarrierUp { ref ifname }
+            | LinkEvent::CarrierDown { ref ifname }
+            | LinkEvent::MtuChanged { ref ifname, .. }
+            | LinkEvent::HwAddrChanged { ref ifname, .. } => ifname,
+            LinkEvent::Renamed { ref new, .. } => new,
+        }
+    }
+}
+
+enum Received {
+    Messages(Vec<impls::NlMessage>),
+    /// Socket buffer overflowed, some notifications were lost
+    Overrun,
+}
+
+/// Netlink socket, subscribed to multicast groups and registered in the tokio reactor
+struct Subscription {
+    io: PollEvented<impls::NetlinkSocket>,
+}
+
+impl Subscription {
+    fn new(groups: u32) -> Result<Subscription, IfError> {
+        Ok(Subscription {
+            io: PollEvented::new(impls::NetlinkSocket::with_groups(groups)?),
+        })
+    }
+
+    fn poll_recv(&mut self) -> Poll<Received, IfError> {
+        let ready = mio::Ready::readable();
+        try_ready!(self.io.poll_read_ready(ready));
+
+        match self.io.get_mut().try_recv_messages() {
+            Ok(Some(messages)) => Ok(Async::Ready(Received::Messages(messages))),
+            Ok(None) => {
+                self.io.clear_read_ready(ready)?;
+                Ok(Async::NotReady)
+            }
+            Err(IfError::Nix(nix::Error::Sys(Errno::ENOBUFS))) => {
+                Ok(Async::Ready(Received::Overrun))
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Stream of `LinkEvent`s of all interfaces
+///
+/// The kernel reports the full state of the interface on every change, so the stream keeps
+/// the last known state of each interface and yields the difference.
+pub struct LinkEvents {
+    subscription: Subscription,
+    links: HashMap<libc::c_int, impls::LinkRecord>,
+    pending: VecDeque<LinkEvent>,
+}
+
+impl LinkEvents {
+    /// Subscribes to the changes. Should be polled within the tokio runtime
+    pub fn new() -> Result<LinkEvents, IfError> {
+        // subscribe before taking the snapshot, so that no change is missed in between
+        let subscription = Subscription::new(RTMGRP_LINK)?;
+        Ok(LinkEvents {
+            subscription,
+            links: LinkEvents::snapshot()?,
+            pending: VecDeque::new(),
+        })
+    }
+
+    fn snapshot() -> Result<HashMap<libc::c_int, impls::LinkRecord>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        Ok(impls::list_links(&mut nl)?
+            .into_iter()
+            .map(|l| (l.index, l))
+            .collect())
+    }
+
+    /// Re-reads all interfaces after the overrun and reports the changes
+    fn resync(&mut self) -> Result<(), IfError> {
+        let mut links = LinkEvents::snapshot()?;
+        let removed: Vec<_> = self
+            .links
+            .keys()
+            .filter(|index| !links.contains_key(index))
+            .cloned()
+            .collect();
+        for index in removed {
+            self.remove(index);
+        }
+        for (_, link) in links.drain() {
+            self.update(link);
+        }
+        Ok(())
+    }
+
+    fn remove(&mut self, index: libc::c_int) {
+        if let Some(old) = self.links.remove(&index) {
+            self.pending
+                .push_back(LinkEvent::Removed { ifname: old.name });
+        }
+    }
+
+    fn update(&mut self, link: impls::LinkRecord) {
+        let old = match self.links.insert(link.index, link.clone()) {
+            Some(old) => old,
+            None => {
+                self.pending
+                    .push_back(LinkEvent::Added { ifname: link.name });
+                return;
+            }
+        };
+
+        if old.name != link.name {
+            self.pending.push_back(LinkEvent::Renamed {
+                old: old.name,
+                new: link.name.clone(),
+            });
+        }
+        let changed = old.flags ^ link.flags;
+        if changed.contains(IfFlags::IFF_UP) {
+            self.pending
+                .push_back(if link.flags.contains(IfFlags::IFF_UP) {
+                    LinkEvent::Up {
+                        ifname: link.name.clone(),
+                    }
+                } else {
+                    LinkEvent::Down {
+                        ifname: link.name.clone(),
+                    }
+                });
+        }
+        if changed.contains(IfFlags::IFF_LOWER_UP) {
+            self.pending
+                .push_back(if link.flags.contains(IfFlags::IFF_LOWER_UP) {
+                    LinkEvent::CarrierUp {
+                        ifname: link.name.clone(),
+                    }
+                } else {
+                    LinkEvent::CarrierDown {
+                        ifname: link.name.clone(),
+                    }
+                });
+        }
+        if old.mtu != link.mtu {
+            self.pending.push_back(LinkEvent::MtuChanged {
+                ifname: link.name.clone(),
+                mtu: link.mtu,
+            });
+        }
+        if old.hw_addr != link.hw_addr {
+            self.pending.push_back(LinkEvent::HwAddrChanged {
+                ifname: link.name,
+                hw_addr: link.hw_addr,
+            });
+        }
+    }
+}
+
+impl Stream for LinkEvents {
+    type Item = LinkEvent;
+    type Error = IfError;
+
+    fn poll(&mut self) -> Poll<Option<LinkEvent>, IfError> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(Async::Ready(Some(event)));
+            }
+
+            match try_ready!(self.subscription.poll_recv()) {
+                Received::Messages(messages) => {
+                    for msg in &messages {
+                        match impls::parse_link(msg) {
+                            Some(ref link) if msg.ty == RTM_DELLINK => self.remove(link.index),
+                            Some(link) => self.update(link),
+                            None => {}
+                        }
+                    }
+                }
+                Received::Overrun => self.resync()?,
+            }
+        }
+    }
+}
+
+/// Change of the interface address, received from `RTNLGRP_IPV4_IFADDR` or `RTNLGRP_IPV6_IFADDR`
+#[derive(Debug, Clone, PartialEq)]
+pub enum AddrEvent {
+    Added { ifname: String, addr: IpNetwork },
+    Removed { ifname: String, addr: IpNetwork },
+}
+
+/// Change of the routing table, received from `RTNLGRP_IPV4_ROUTE` or `RTNLGRP_IPV6_ROUTE`
+#[derive(Debug, Clone, PartialEq)]
+pub enum RouteEvent {
+    Added(Route),
+    Removed(Route),
+}
+
+/// Notifications of the subscription, which also keeps track of interface names
+///
+/// Address and route notifications refer to interfaces by index, so the subscription
+/// always includes `RTNLGRP_LINK` to learn names of the new interfaces.
+struct Notifications {
+    subscription: Subscription,
+    names: HashMap<libc::c_int, String>,
+    received: VecDeque<impls::NlMessage>,
+}
+
+impl Notifications {
+    fn new(groups: u32) -> Result<Notifications, IfError> {
+        let subscription = Subscription::new(groups | RTMGRP_LINK)?;
+        let mut nl = impls::NetlinkSocket::new()?;
+        let names = impls::list_links(&mut nl)?
+            .into_iter()
+            .map(|l| (l.index, l.name))
+            .collect();
+        Ok(Notifications {
+            subscription,
+            names,
+            received: VecDeque::new(),
+        })
+    }
+
+    /// Returns the next message, which is not a link notification.
+    /// Lost notifications can't be recovered, so the overrun is reported as `ENOBUFS` error
+    fn poll_message(&mut self) -> Poll<impls::NlMessage, IfError> {
+        loop {
+            while let Some(msg) = self.received.pop_front() {
+                match impls::parse_link(&msg) {
+                    Some(link) => {
+                        if msg.ty == RTM_NEWLINK {
+                            self.names.insert(link.index, link.name);
+                        } else {
+                            self.names.remove(&link.index);
+                        }
+                    }
+                    None => return Ok(Async::Ready(msg)),
+                }
+            }
+
+            match try_ready!(self.subscription.poll_recv()) {
+                Received::Messages(messages) => self.received.extend(messages),
+                Received::Overrun => return Err(nix::Error::Sys(Errno::ENOBUFS).into()),
+            }
+        }
+    }
+}
+
+/// Stream of `AddrEvent`s of the single interface or of all interfaces
+pub struct AddrEvents {
+    notifications: Notifications,
+    index: Option<libc::c_int>,
+}
+
+impl AddrEvents {
+    /// Subscribes to address changes of all interfaces. Should be polled within the tokio runtime
+    pub fn new() -> Result<AddrEvents, IfError> {
+        Ok(AddrEvents {
+            notifications: Notifications::new(RTMGRP_IPV4_IFADDR | RTMGRP_IPV6_IFADDR)?,
+            index: None,
+        })
+    }
+
+    fn parse(&self, msg: &impls::NlMessage) -> Option<AddrEvent> {
+        let record = impls::parse_addr(msg)?;
+        if self.index.is_some() && self.index != Some(record.index) {
+            return None;
+        }
+        let ifname = self.notifications.names.get(&record.index)?.clone();
+        let addr = IpNetwork::new(record.addr, record.prefix_len).ok()?;
+        Some(if msg.ty == RTM_DELADDR {
+            AddrEvent::Removed { ifname, addr }
+        } else {
+            AddrEvent::Added { ifname, addr }
+        })
+    }
+}
+
+impl Stream for AddrEvents {
+    type Item = AddrEvent;
+    type Error = IfError;
+
+    fn poll(&mut self) -> Poll<Option<AddrEvent>, IfError> {
+        loop {
+            let msg = try_ready!(self.notifications.poll_message());
+            if let Some(event) = self.parse(&msg) {
+                return Ok(Async::Ready(Some(event)));
+            }
+        }
+    }
+}
+
+/// Stream of `RouteEvent`s of all routing tables
+pub struct RouteEvents {
+    notifications: Notifications,
+    index: Option<libc::c_int>,
+}
+
+impl RouteEvents {
+    /// Subscribes to route changes. Should be polled within the tokio runtime
+    pub fn new() -> Result<RouteEvents, IfError> {
+        Ok(RouteEvents {
+            notifications: Notifications::new(RTMGRP_IPV4_ROUTE | RTMGRP_IPV6_ROUTE)?,
+            index: None,
+        })
+    }
+
+    fn parse(&self, msg: &impls::NlMessage) -> Option<RouteEvent> {
+        let names = &self.notifications.names;
+        let route = impls::parse_route(msg, names)?;
+        if let Some(index) = self.index {
+            if route.iface.is_none() || route.iface.as_ref() != names.get(&index) {
+                return None;
+            }
+        }
+        Some(if msg.ty == RTM_DELROUTE {
+            RouteEvent::Removed(route)
+        } else {
+            RouteEvent::Added(route)
+        })
+    }
+}
+
+impl Stream for RouteEvents {
+    type Item = RouteEvent;
+    type Error = IfError;
+
+    fn poll(&mut self) -> Poll<Option<RouteEvent>, IfError> {
+        loop {
+            let msg = try_ready!(self.notifications.poll_message());
+            if let Some(event) = self.parse(&msg) {
+                return Ok(Async::Ready(Some(event)));
+            }
+        }
+    }
+}
+
+impl Iface {
+    /// Stream of address changes of this interface
+    pub fn addr_events(&self) -> Result<AddrEvents, IfError> {
+        let mut events = AddrEvents::new()?;
+        events.index = Some(self.link_index()?);
+        Ok(events)
+    }
+
+    /// Stream of changes of routes, going through this interface
+    pub fn route_events(&self) -> Result<RouteEvents, IfError> {
+        let mut events = RouteEvents::new()?;
+        events.index = Some(self.link_index()?);
+        Ok(events)
+    }
+
+    fn link_index(&self) -> Result<libc::c_int, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        Ok(impls::get_link_by_name(&mut nl, &self.ifname)?.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::runtime::current_thread::Runtime;
+
+    #[test]
+    fn test_link_events() {
+        let events = LinkEvents::new().expect("subscribe");
+
+        let ctl_fd = impls::new_control_socket().unwrap();
+        impls::create_bridge(&ctl_fd, "ifctlevent0").expect("create bridge");
+        Iface::up_by_name("ifctlevent0").unwrap();
+        Iface::down_by_name("ifctlevent0").unwrap();
+        impls::remove_bridge(&ctl_fd, "ifctlevent0").expect("remove bridge");
+
+        let received = Runtime::new()
+            .unwrap()
+            .block_on(
+                events
+                    .filter(|e| match *e {
+                        LinkEvent::Added { .. }
+                        | LinkEvent::Up { .. }
+                        | LinkEvent::Down { .. }
+                        | LinkEvent::Removed { .. } => e.ifname() == "ifctlevent0",
+                        _ => false,
+                    })
+                    .take(4)
+                    .collect(),
+            )
+            .expect("receive events");
+
+        let ifname = "ifctlevent0".to_owned();
+        assert_eq!(
+            received,
+            vec![
+                LinkEvent::Added {
+                    ifname: ifname.clone()
+                },
+                LinkEvent::Up {
+                    ifname: ifname.clone()
+                },
+                LinkEvent::Down {
+                    ifname: ifname.clone()
+                },
+                LinkEvent::Removed { ifname },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_addr_route_events() {
+        let ctl_fd = impls::new_control_socket().unwrap();
+        impls::create_bridge(&ctl_fd, "ifctlevent1").expect("create bridge");
+        let mut iface = Iface::find_by_name("ifctlevent1").unwrap();
+        iface.up().unwrap();
+        let addr_events = iface.addr_events().expect("subscribe to addresses");
+        let route_events = RouteEvents::new().expect("subscribe to routes");
+
+        let addr: IpNetwork = "10.254.20.1/24".parse().unwrap();
+        iface.add_addr(addr).expect("add addr");
+        let mut route = Route::new("10.253.20.0/24".parse().unwrap());
+        route.gateway = Some("10.254.20.2".parse().unwrap());
+        route.add().expect("add route");
+        route.delete().expect("delete route");
+        iface.del_addr(addr.ip()).expect("del addr");
+
+        iface.down().unwrap();
+        impls::remove_bridge(&ctl_fd, "ifctlevent1").expect("remove bridge");
+
+        let mut rt = Runtime::new().unwrap();
+        let received = rt
+            .block_on(addr_events.take(2).collect())
+            .expect("receive address events");
+        let ifname = "ifctlevent1".to_owned();
+        assert_eq!(
+            received,
+            vec![
+                AddrEvent::Added {
+                    ifname: ifname.clone(),
+                    addr
+                },
+                AddrEvent::Removed {
+                    ifname: ifname.clone(),
+                    addr
+                },
+            ]
+        );
+
+        let received = rt
+            .block_on(
+                route_events
+                    .filter(|e| match *e {
+                        RouteEvent::Added(ref r) | RouteEvent::Removed(ref r) => {
+                            r.destination == route.destination
+                        }
+                    })
+                    .take(2)
+                    .collect(),
+            )
+            .expect("receive route events");
+        match received.as_slice() {
+            [RouteEvent::Added(added), RouteEvent::Removed(removed)] => {
+                assert_eq!(added.gateway, route.gateway);
+                assert_eq!(added.iface.as_ref(), Some(&ifname));
+                assert_eq!(removed.gateway, route.gateway);
+            }
+            _ => panic!("unexpected route events: {:?}", received),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use eui48::MacAddress;
+use ifstructs::{NtfFlags, NudState};
+use impls;
+use std::net::IpAddr;
+use IfError;
+
+/// Forwarding database entry of a bridge, a bridge port or a VXLAN interface
+/// (`bridge fdb add <lladdr> dev <iface> ...`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FdbEntry {
+    /// Name of the interface
+    pub iface: String,
+    pub lladdr: MacAddress,
+    /// Remote VXLAN endpoint
+    pub dst: Option<IpAddr>,
+    pub vlan: Option<u16>,
+    /// VNI of the remote endpoint, if it differs from the one of the interface
+    pub vni: Option<u32>,
+    /// UDP port of the remote endpoint, if it differs from the one of the interface
+    pub port: Option<u16>,
+    pub state: NudState,
+    pub flags: NtfFlags,
+}
+
+impl FdbEntry {
+    /// Permanent entry of the interface itself (`NTF_SELF`)
+    pub fn new(iface: &str, lladdr: MacAddress) -> FdbEntry {
+        FdbEntry {
+            iface: iface.to_owned(),
+            lladdr,
+            dst: None,
+            vlan: None,
+            vni: None,
+            port: None,
+            state: NudState::NUD_PERMANENT,
+            flags: NtfFlags::NTF_SELF,
+        }
+    }
+
+    /// Static entry of the bridge port in the database of its bridge (`NTF_MASTER`)
+    pub fn new_master(port: &str, lladdr: MacAddress) -> FdbEntry {
+        FdbEntry {
+            state: NudState::NUD_NOARP,
+            flags: NtfFlags::NTF_MASTER,
+            ..FdbEntry::new(port, lladdr)
+        }
+    }
+
+    /// Entries of all interfaces
+    pub fn list() -> Result<Vec<FdbEntry>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::list_fdb(&mut nl)
+    }
+
+    pub fn add(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::add_fdb(&mut nl, self)
+    }
+
+    /// Adds one more destination of the same address (`bridge fdb append ...`). With
+    /// `00:00:00:00:00:00` it makes VXLAN flood unknown frames to all appended `dst`
+    pub fn append(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::append_fdb(&mut nl, self)
+    }
+
+    /// Removes the entry. For VXLAN only the destination matching `dst` is removed
+    pub fn delete(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::del_fdb(&mut nl, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Vxlan;
+
+    #[test]
+    fn test_vxlan_fdb() {
+        let mut vxlan = Vxlan::new("ifctlfdb0", 4218);
+        vxlan.local = Some("10.254.15.1".parse().unwrap());
+        let iface = vxlan.create().expect("create vxlan");
+
+        let mut flood1 = FdbEntry::new("ifctlfdb0", MacAddress::nil());
+        flood1.dst = Some("10.254.15.2".parse().unwrap());
+        let mut flood2 = flood1.clone();
+        flood2.dst = Some("10.254.15.3".parse().unwrap());
+        let mut host = FdbEntry::new(
+            "ifctlfdb0",
+            MacAddress::parse_str("02:00:00:00:15:01").unwrap(),
+        );
+        host.dst = Some("10.254.15.4".parse().unwrap());
+        host.vni = Some(4219);
+        flood1.append().expect("append fdb entry");
+        flood2.append().expect("append fdb entry");
+        host.add().expect("add fdb entry");
+
+        let entries = iface.fdb_entries().expect("list fdb");
+        assert!(entries.contains(&flood1));
+        assert!(entries.contains(&flood2));
+        assert!(entries.contains(&host));
+
+        flood1.delete().expect("delete fdb entry");
+        let entries = iface.fdb_entries().unwrap();
+        assert!(!entries.contains(&flood1));
+        assert!(entries.contains(&flood2));
+
+        iface.delete().expect("delete vxlan");
+    }
+}
@@ -2,15 +2,17 @@ use ifstructs::{
     self, brcmd, ifaliasreq, ifbreq, ifdrv, ifgroupreq, ifreq, rt_msghdr, IfName, RtfFlags,
     RtmAddrFlags,
 };
-use IfError;
+use ipnetwork::{self, IpNetwork};
 use libc;
 use nix;
 use nix::sys::socket::SockAddr;
 use std::ffi::CString;
 use std::io::Seek;
 use std::mem;
+use std::net::IpAddr;
 use std::os::unix::io::AsRawFd;
 use std::{io, ptr};
+use {IfError, Route, RouteProtocol, RouteType};
 
 // #define	SIOCAIFADDR	 _IOW('i', 43, struct ifaliasreq)/* add/chg IF alias */
 ioctl_write_ptr!(iface_add_addr, b'i', 43, ifaliasreq);
@@ -56,6 +58,23 @@ pub fn create_bridge<F: AsRawFd>(ctl_fd: &F, bridge_ifname: &str) -> Result<(),
     Ok(())
 }
 
+pub fn rename_iface<F: AsRawFd>(ctl_fd: &F, ifname: &str, new_ifname: &str) -> Result<(), IfError> {
+    // the same limits apply to the new name
+    ifreq::from_name(new_ifname)?;
+
+    let mut req = ifreq::from_name(ifname)?;
+
+    req.ifr_ifru.ifru_data = CString::new(new_ifname).unwrap().into_raw();
+
+    let res = unsafe { ioctl_set_iface_name(ctl_fd.as_raw_fd(), &mut req) };
+
+    unsafe { CString::from_raw(req.ifr_ifru.ifru_data) };
+
+    res?;
+
+    Ok(())
+}
+
 pub fn remove_bridge<F: AsRawFd>(ctl_fd: &F, bridge_ifname: &str) -> Result<(), IfError> {
     let mut req = ifreq::from_name(bridge_ifname)?;
     unsafe { ioctl_iface_destroy(ctl_fd.as_raw_fd(), &mut req) }?;
@@ -109,6 +128,67 @@ pub struct RouteRecord {
     flags: RtfFlags,
 }
 
+fn sockaddr_ip(addr: &Option<SockAddr>) -> Option<IpAddr> {
+    match *addr {
+        Some(SockAddr::Inet(inet)) => Some(inet.to_std().ip()),
+        _ => None,
+    }
+}
+
+impl RouteRecord {
+    /// `None` for link-level (ARP/NDP) entries, which have no IP destination
+    pub fn to_route(&self) -> Option<Route> {
+        let destination = sockaddr_ip(&self.destination)?;
+        let max_prefix = match destination {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        let prefix = match sockaddr_ip(&self.netmask) {
+            Some(mask) => ipnetwork::ip_mask_to_prefix(mask).ok()?,
+            None if self.flags.contains(RtfFlags::RTF_HOST) => max_prefix,
+            // the mask of the default route is empty
+            None if destination.is_unspecified() => 0,
+            None => max_prefix,
+        };
+
+        let kind = if self.flags.contains(RtfFlags::RTF_BLACKHOLE) {
+            RouteType::Blackhole
+        } else if self.flags.contains(RtfFlags::RTF_REJECT) {
+            RouteType::Unreachable
+        } else if self.flags.contains(RtfFlags::RTF_LOCAL) {
+            RouteType::Local
+        } else if self.flags.contains(RtfFlags::RTF_BROADCAST) {
+            RouteType::Broadcast
+        } else if self.flags.contains(RtfFlags::RTF_MULTICAST) {
+            RouteType::Multicast
+        } else {
+            RouteType::Unicast
+        };
+        let protocol = if self.flags.contains(RtfFlags::RTF_STATIC) {
+            RouteProtocol::Static
+        } else if self.flags.contains(RtfFlags::RTF_DYNAMIC) {
+            RouteProtocol::Redirect
+        } else {
+            RouteProtocol::Kernel
+        };
+
+        Some(Route {
+            destination: IpNetwork::new(destination, prefix).ok()?,
+            // gateway of the directly connected network is a link-level address
+            gateway: sockaddr_ip(&self.gateway),
+            iface: Some(self.iface.name().to_owned()),
+            nexthops: vec![],
+            source: sockaddr_ip(&self.iface_addr),
+            metric: None,
+            // only the default FIB is dumped
+            table: Route::TABLE_MAIN,
+            protocol,
+            scope: None,
+            kind,
+        })
+    }
+}
+
 pub fn read_sockaddr_if_flag(
     i: &mut usize,
     buf: &[u8],
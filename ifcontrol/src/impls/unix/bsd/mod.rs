@@ -10,6 +10,19 @@ ioctl_readwrite!(iface_get_flags, b'i', 17, ifreq);
 // #define	SIOCSIFFLAGS	 _IOW('i', 16, struct ifreq)	/* set ifnet flags */
 ioctl_write_ptr!(iface_set_flags, b'i', 16, ifreq);
 
+// #define	SIOCGIFMTU	_IOWR('i', 51, struct ifreq)	/* get IF mtu */
+#[cfg(not(any(target_os = "openbsd", target_os = "netbsd", target_os = "bitrig")))]
+ioctl_readwrite!(iface_get_mtu, b'i', 51, ifreq);
+// #define	SIOCSIFMTU	 _IOW('i', 52, struct ifreq)	/* set IF mtu */
+#[cfg(not(any(target_os = "openbsd", target_os = "netbsd", target_os = "bitrig")))]
+ioctl_write_ptr!(iface_set_mtu, b'i', 52, ifreq);
+// #define	SIOCGIFMTU	_IOWR('i', 126, struct ifreq)	/* get ifnet mtu */
+#[cfg(any(target_os = "openbsd", target_os = "netbsd", target_os = "bitrig"))]
+ioctl_readwrite!(iface_get_mtu, b'i', 126, ifreq);
+// #define	SIOCSIFMTU	 _IOW('i', 127, struct ifreq)	/* set ifnet mtu */
+#[cfg(any(target_os = "openbsd", target_os = "netbsd", target_os = "bitrig"))]
+ioctl_write_ptr!(iface_set_mtu, b'i', 127, ifreq);
+
 //#define	SIOCAIFADDR	 _IOW('i', 26, struct ifaliasreq)/* add/chg IF alias */
 #[cfg(not(target_os = "freebsd"))]
 ioctl_write_ptr!(iface_add_addr, b'i', 26, ifaliasreq);
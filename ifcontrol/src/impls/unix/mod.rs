@@ -100,7 +100,44 @@ pub fn down<F: AsRawFd>(ctl_fd: &F, ifname: &str) -> Result<(), IfError> {
     Ok(())
 }
 
-#[cfg(not(target_os = "android"))]
+/// Clears `IFF_UP` even if the iface has no carrier, unlike `down`.
+/// Returns `true`, if the flag was set
+pub fn set_admin_down<F: AsRawFd>(ctl_fd: &F, ifname: &str) -> Result<bool, IfError> {
+    let mut req = get_iface_ifreq(ctl_fd, ifname)?;
+
+    let mut flags = unsafe { req.get_flags() };
+    if !flags.contains(IfFlags::IFF_UP) {
+        return Ok(false);
+    }
+
+    flags.remove(IfFlags::IFF_UP);
+    flags.remove(IfFlags::IFF_RUNNING);
+
+    unsafe { req.set_flags(flags) };
+
+    unsafe { iface_set_flags(ctl_fd.as_raw_fd(), &req) }?;
+    Ok(true)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn get_mtu<F: AsRawFd>(ctl_fd: &F, ifname: &str) -> Result<u32, IfError> {
+    let mut req = ifreq::from_name(ifname)?;
+    ti!(unsafe { iface_get_mtu(ctl_fd.as_raw_fd(), &mut req) })?;
+
+    Ok(unsafe { req.get_mtu() } as u32)
+}
+
+pub fn set_mtu<F: AsRawFd>(ctl_fd: &F, ifname: &str, mtu: u32) -> Result<(), IfError> {
+    let mut req = ifreq::from_name(ifname)?;
+
+    unsafe { req.set_mtu(mtu as libc::c_int) };
+
+    ti!(unsafe { iface_set_mtu(ctl_fd.as_raw_fd(), &req) })?;
+
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
 pub fn get_all_addresses() -> Result<nix::ifaddrs::InterfaceAddressIterator, IfError> {
     Ok(nix::ifaddrs::getifaddrs()?)
 }
@@ -0,0 +1,132 @@
+use super::netlink::{as_bytes, attr_ip, attr_u32, NetlinkSocket, NlMessage, NlMsgBuilder};
+use ifstructs::{
+    ifa_cacheinfo, ifaddrmsg, IfaFlags, IFA_ADDRESS, IFA_BROADCAST, IFA_CACHEINFO, IFA_FLAGS,
+    IFA_LOCAL, INFINITY_LIFE_TIME, NLM_F_CREATE, NLM_F_EXCL, RTM_DELADDR, RTM_GETADDR, RTM_NEWADDR,
+};
+use ipnetwork::IpNetwork;
+use libc;
+use std::mem;
+use std::net::IpAddr;
+use IfError;
+
+/// Address, as reported by `RTM_NEWADDR` or `RTM_DELADDR`
+#[derive(Debug, Clone)]
+pub struct AddrRecord {
+    pub index: libc::c_int,
+    pub prefix_len: u8,
+    pub addr: IpAddr,
+    /// `RT_SCOPE_*` scope
+    pub scope: u8,
+    pub flags: IfaFlags,
+}
+
+pub fn parse_addr(msg: &NlMessage) -> Option<AddrRecord> {
+    if msg.ty != RTM_NEWADDR && msg.ty != RTM_DELADDR {
+        return None;
+    }
+    let ifa: ifaddrmsg = msg.header()?;
+    let mut address = None;
+    let mut local = None;
+    let mut flags = IfaFlags::from_bits_truncate(u32::from(ifa.ifa_flags));
+    for (ty, data) in msg.attrs::<ifaddrmsg>() {
+        match ty {
+            IFA_ADDRESS => address = attr_ip(data),
+            IFA_LOCAL => local = attr_ip(data),
+            // all the flags, which don't fit into ifa_flags
+            IFA_FLAGS => flags = IfaFlags::from_bits_truncate(attr_u32(data)?),
+            _ => {}
+        }
+    }
+    // for point-to-point links IFA_ADDRESS contains peer address
+    Some(AddrRecord {
+        index: ifa.ifa_index as libc::c_int,
+        prefix_len: ifa.ifa_prefixlen,
+        addr: local.or(address)?,
+        scope: ifa.ifa_scope,
+        flags,
+    })
+}
+
+fn addr_msg(ip: IpAddr, prefix_len: u8, scope: u8, index: libc::c_int) -> ifaddrmsg {
+    let mut ifa: ifaddrmsg = unsafe { mem::zeroed() };
+    ifa.ifa_family = match ip {
+        IpAddr::V4(_) => libc::AF_INET as u8,
+        IpAddr::V6(_) => libc::AF_INET6 as u8,
+    };
+    ifa.ifa_prefixlen = prefix_len;
+    ifa.ifa_scope = scope;
+    ifa.ifa_index = index as u32;
+    ifa
+}
+
+pub fn list_addrs(nl: &mut NetlinkSocket) -> Result<Vec<AddrRecord>, IfError> {
+    let mut ifa: ifaddrmsg = unsafe { mem::zeroed() };
+    ifa.ifa_family = libc::AF_UNSPEC as u8;
+    Ok(nl
+        .dump(NlMsgBuilder::new(RTM_GETADDR, 0, &ifa))?
+        .iter()
+        .filter_map(parse_addr)
+        .collect())
+}
+
+pub fn add_addr(
+    nl: &mut NetlinkSocket,
+    index: libc::c_int,
+    cidr: IpNetwork,
+    opts: &::AddrOptions,
+) -> Result<(), IfError> {
+    let scope = opts.scope.map(u8::from).unwrap_or(0);
+    let ifa = addr_msg(cidr.ip(), cidr.prefix(), scope, index);
+    let mut req = NlMsgBuilder::new(RTM_NEWADDR, NLM_F_CREATE | NLM_F_EXCL, &ifa);
+    req.attr_ip(IFA_LOCAL, cidr.ip());
+    req.attr_ip(IFA_ADDRESS, cidr.ip());
+
+    if let IpNetwork::V4(net) = cidr {
+        if let Some(brd) = opts.broadcast {
+            req.attr_ip(IFA_BROADCAST, IpAddr::V4(brd));
+        } else if net.prefix() < 31 {
+            req.attr_ip(IFA_BROADCAST, IpAddr::V4(net.broadcast()));
+        }
+    }
+
+    if opts.valid_lifetime.is_some() || opts.preferred_lifetime.is_some() {
+        let valid = opts.valid_lifetime.unwrap_or(INFINITY_LIFE_TIME);
+        let cacheinfo = ifa_cacheinfo {
+            ifa_prefered: opts.preferred_lifetime.unwrap_or(valid),
+            ifa_valid: valid,
+            cstamp: 0,
+            tstamp: 0,
+        };
+        req.attr(IFA_CACHEINFO, unsafe { as_bytes(&cacheinfo) });
+    }
+
+    nl.request(req)
+}
+
+pub fn del_addr(
+    nl: &mut NetlinkSocket,
+    index: libc::c_int,
+    ip: IpAddr,
+    prefix_len: u8,
+) -> Result<(), IfError> {
+    let ifa = addr_msg(ip, prefix_len, 0, index);
+    let mut req = NlMsgBuilder::new(RTM_DELADDR, 0, &ifa);
+    req.attr_ip(IFA_LOCAL, ip);
+    req.attr_ip(IFA_ADDRESS, ip);
+    nl.request(req)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_addrs() {
+        let mut nl = NetlinkSocket::new().unwrap();
+        let lo = ::impls::get_link_by_name(&mut nl, "lo").expect("get lo");
+        assert!(list_addrs(&mut nl)
+            .expect("list addrs")
+            .iter()
+            .any(|a| a.index == lo.index && a.addr.is_loopback()));
+    }
+}
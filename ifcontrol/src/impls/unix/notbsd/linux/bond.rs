@@ -0,0 +1,244 @@
+use super::link::{
+    change_link_request, get_link_by_index, get_link_by_name, link_info_attr, list_links,
+    new_link_request, LinkRecord,
+};
+use super::netlink::{attr_u16, attr_u32, attr_u8, NetlinkSocket, NlAttrs, NlMsgBuilder};
+use eui48::MacAddress;
+use ifstructs::{
+    BOND_LINK_BACK, BOND_LINK_DOWN, BOND_LINK_FAIL, BOND_LINK_UP, BOND_MODE_8023AD,
+    BOND_MODE_ACTIVEBACKUP, BOND_MODE_ALB, BOND_MODE_BROADCAST, BOND_MODE_ROUNDROBIN,
+    BOND_MODE_TLB, BOND_MODE_XOR, BOND_STATE_ACTIVE, BOND_XMIT_POLICY_ENCAP23,
+    BOND_XMIT_POLICY_ENCAP34, BOND_XMIT_POLICY_LAYER2, BOND_XMIT_POLICY_LAYER23,
+    BOND_XMIT_POLICY_LAYER34, IFLA_BOND_ACTIVE_SLAVE, IFLA_BOND_MIIMON, IFLA_BOND_MODE,
+    IFLA_BOND_PRIMARY, IFLA_BOND_SLAVE_LINK_FAILURE_COUNT, IFLA_BOND_SLAVE_MII_STATUS,
+    IFLA_BOND_SLAVE_PERM_HWADDR, IFLA_BOND_SLAVE_QUEUE_ID, IFLA_BOND_SLAVE_STATE,
+    IFLA_BOND_XMIT_HASH_POLICY,
+};
+use libc;
+use {Bond, BondMode, BondSlave, IfError, MiiStatus, XmitHashPolicy};
+
+fn mode_to_u8(mode: &BondMode) -> u8 {
+    match *mode {
+        BondMode::BalanceRr => BOND_MODE_ROUNDROBIN,
+        BondMode::ActiveBackup => BOND_MODE_ACTIVEBACKUP,
+        BondMode::BalanceXor => BOND_MODE_XOR,
+        BondMode::Broadcast => BOND_MODE_BROADCAST,
+        BondMode::Lacp => BOND_MODE_8023AD,
+        BondMode::BalanceTlb => BOND_MODE_TLB,
+        BondMode::BalanceAlb => BOND_MODE_ALB,
+        BondMode::Other(v) => v,
+    }
+}
+
+fn mode_from_u8(mode: u8) -> BondMode {
+    match mode {
+        BOND_MODE_ROUNDROBIN => BondMode::BalanceRr,
+        BOND_MODE_ACTIVEBACKUP => BondMode::ActiveBackup,
+        BOND_MODE_XOR => BondMode::BalanceXor,
+        BOND_MODE_BROADCAST => BondMode::Broadcast,
+        BOND_MODE_8023AD => BondMode::Lacp,
+        BOND_MODE_TLB => BondMode::BalanceTlb,
+        BOND_MODE_ALB => BondMode::BalanceAlb,
+        v => BondMode::Other(v),
+    }
+}
+
+fn policy_to_u8(policy: &XmitHashPolicy) -> u8 {
+    match *policy {
+        XmitHashPolicy::Layer2 => BOND_XMIT_POLICY_LAYER2,
+        XmitHashPolicy::Layer34 => BOND_XMIT_POLICY_LAYER34,
+        XmitHashPolicy::Layer23 => BOND_XMIT_POLICY_LAYER23,
+        XmitHashPolicy::Encap23 => BOND_XMIT_POLICY_ENCAP23,
+        XmitHashPolicy::Encap34 => BOND_XMIT_POLICY_ENCAP34,
+        XmitHashPolicy::Other(v) => v,
+    }
+}
+
+fn policy_from_u8(policy: u8) -> XmitHashPolicy {
+    match policy {
+        BOND_XMIT_POLICY_LAYER2 => XmitHashPolicy::Layer2,
+        BOND_XMIT_POLICY_LAYER34 => XmitHashPolicy::Layer34,
+        BOND_XMIT_POLICY_LAYER23 => XmitHashPolicy::Layer23,
+        BOND_XMIT_POLICY_ENCAP23 => XmitHashPolicy::Encap23,
+        BOND_XMIT_POLICY_ENCAP34 => XmitHashPolicy::Encap34,
+        v => XmitHashPolicy::Other(v),
+    }
+}
+
+fn bond_request(bond: &Bond, primary: Option<libc::c_int>) -> NlMsgBuilder {
+    let mut req = new_link_request(&bond.name);
+    link_info_attr(&mut req, "bond", |req| {
+        req.attr_u8(IFLA_BOND_MODE, mode_to_u8(&bond.mode));
+        req.attr_u32(IFLA_BOND_MIIMON, bond.miimon);
+        req.attr_u8(
+            IFLA_BOND_XMIT_HASH_POLICY,
+            policy_to_u8(&bond.xmit_hash_policy),
+        );
+        if let Some(primary) = primary {
+            req.attr_u32(IFLA_BOND_PRIMARY, primary as u32);
+        }
+    });
+    req
+}
+
+pub fn create_bond(nl: &mut NetlinkSocket, bond: &Bond) -> Result<(), IfError> {
+    let primary = match bond.primary {
+        Some(ref primary) => Some(get_link_by_name(nl, primary)?.index),
+        None => None,
+    };
+    nl.request(bond_request(bond, primary))
+}
+
+fn bond_primary_request(index: libc::c_int, primary: libc::c_int) -> NlMsgBuilder {
+    let mut req = change_link_request(index);
+    link_info_attr(&mut req, "bond", |req| {
+        req.attr_u32(IFLA_BOND_PRIMARY, primary as u32);
+    });
+    req
+}
+
+pub fn set_bond_primary(nl: &mut NetlinkSocket, ifname: &str, slave: &str) -> Result<(), IfError> {
+    let index = get_link_by_name(nl, ifname)?.index;
+    let primary = get_link_by_name(nl, slave)?.index;
+    nl.request(bond_primary_request(index, primary))
+}
+
+/// Parses settings of the `link` with "bond" kind. Returns them along with the primary and
+/// the active slave indexes, as `primary` is left unset
+fn parse_bond(link: &LinkRecord) -> Option<(Bond, Option<libc::c_int>, Option<libc::c_int>)> {
+    if link.kind.as_deref() != Some("bond") {
+        return None;
+    }
+    let mut bond = Bond::new(&link.name, BondMode::BalanceRr);
+    let (mut primary, mut active) = (None, None);
+    for (ty, data) in NlAttrs::new(&link.info_data) {
+        match ty {
+            IFLA_BOND_MODE => bond.mode = mode_from_u8(attr_u8(data)?),
+            IFLA_BOND_MIIMON => bond.miimon = attr_u32(data)?,
+            IFLA_BOND_XMIT_HASH_POLICY => bond.xmit_hash_policy = policy_from_u8(attr_u8(data)?),
+            IFLA_BOND_PRIMARY => primary = attr_u32(data).map(|i| i as libc::c_int),
+            IFLA_BOND_ACTIVE_SLAVE => active = attr_u32(data).map(|i| i as libc::c_int),
+            _ => {}
+        }
+    }
+    Some((bond, primary, active))
+}
+
+pub fn get_bond(nl: &mut NetlinkSocket, ifname: &str) -> Result<Bond, IfError> {
+    let link = get_link_by_name(nl, ifname)?;
+    let (mut bond, primary, _) = parse_bond(&link).ok_or(IfError::NotFound)?;
+    if let Some(primary) = primary {
+        bond.primary = Some(get_link_by_index(nl, primary)?.name);
+    }
+    Ok(bond)
+}
+
+pub fn get_bond_active_slave(
+    nl: &mut NetlinkSocket,
+    ifname: &str,
+) -> Result<Option<String>, IfError> {
+    let link = get_link_by_name(nl, ifname)?;
+    match parse_bond(&link).ok_or(IfError::NotFound)? {
+        (_, _, Some(active)) => Ok(Some(get_link_by_index(nl, active)?.name)),
+        _ => Ok(None),
+    }
+}
+
+/// Parses state of the `link`, enslaved to the bond
+fn parse_bond_slave(link: &LinkRecord) -> Option<BondSlave> {
+    if link.slave_kind.as_deref() != Some("bond") {
+        return None;
+    }
+    let mut slave = BondSlave {
+        name: link.name.clone(),
+        active: false,
+        mii_status: MiiStatus::Down,
+        link_failure_count: 0,
+        perm_hw_addr: None,
+        queue_id: 0,
+    };
+    for (ty, data) in NlAttrs::new(&link.slave_info_data) {
+        match ty {
+            IFLA_BOND_SLAVE_STATE => slave.active = attr_u8(data)? == BOND_STATE_ACTIVE,
+            IFLA_BOND_SLAVE_MII_STATUS => {
+                slave.mii_status = match attr_u8(data)? {
+                    BOND_LINK_UP => MiiStatus::Up,
+                    BOND_LINK_FAIL => MiiStatus::Fail,
+                    BOND_LINK_DOWN => MiiStatus::Down,
+                    BOND_LINK_BACK => MiiStatus::Back,
+                    v => MiiStatus::Other(v),
+                }
+            }
+            IFLA_BOND_SLAVE_LINK_FAILURE_COUNT => slave.link_failure_count = attr_u32(data)?,
+            IFLA_BOND_SLAVE_PERM_HWADDR => slave.perm_hw_addr = MacAddress::from_bytes(data).ok(),
+            IFLA_BOND_SLAVE_QUEUE_ID => slave.queue_id = attr_u16(data)?,
+            _ => {}
+        }
+    }
+    Some(slave)
+}
+
+pub fn list_bond_slaves(nl: &mut NetlinkSocket, ifname: &str) -> Result<Vec<BondSlave>, IfError> {
+    let bond = get_link_by_name(nl, ifname)?;
+    Ok(list_links(nl)?
+        .iter()
+        .filter(|l| l.master == Some(bond.index))
+        .filter_map(parse_bond_slave)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::link::{link_message, link_request_attrs, parse_link};
+    use super::*;
+
+    #[test]
+    fn test_bond_request() {
+        let mut bond = Bond::new("bond0", BondMode::Lacp);
+        bond.miimon = 100;
+        bond.xmit_hash_policy = XmitHashPolicy::Layer34;
+
+        let expected: Vec<u8> = [
+            // IFLA_IFNAME
+            &[10, 0, 3, 0][..],
+            b"bond0\0\0\0",
+            // IFLA_LINKINFO, IFLA_INFO_KIND
+            &[52, 0, 18, 0x80, 9, 0, 1, 0],
+            b"bond\0\0\0\0",
+            // IFLA_INFO_DATA, IFLA_BOND_MODE: BOND_MODE_8023AD
+            &[36, 0, 2, 0x80, 5, 0, 1, 0, 4, 0, 0, 0],
+            // IFLA_BOND_MIIMON
+            &[8, 0, 3, 0, 100, 0, 0, 0],
+            // IFLA_BOND_XMIT_HASH_POLICY: BOND_XMIT_POLICY_LAYER34
+            &[5, 0, 14, 0, 1, 0, 0, 0],
+            // IFLA_BOND_PRIMARY
+            &[8, 0, 11, 0, 3, 0, 0, 0],
+        ]
+        .concat();
+        assert_eq!(link_request_attrs(bond_request(&bond, Some(3))), expected);
+
+        let link = parse_link(&link_message(10, &expected)).unwrap();
+        assert_eq!(parse_bond(&link), Some((bond, Some(3), None)));
+    }
+
+    #[test]
+    fn test_unknown_values() {
+        let attrs: Vec<u8> = [
+            &[10, 0, 3, 0][..],
+            b"bond0\0\0\0",
+            &[52, 0, 18, 0, 9, 0, 1, 0],
+            b"bond\0\0\0\0",
+            // mode 7, xmit hash policy 5 and the active slave
+            &[36, 0, 2, 0, 5, 0, 1, 0, 7, 0, 0, 0],
+            &[5, 0, 14, 0, 5, 0, 0, 0],
+            &[8, 0, 2, 0, 4, 0, 0, 0],
+            &[8, 0, 3, 0, 0, 0, 0, 0],
+        ]
+        .concat();
+        let mut bond = Bond::new("bond0", BondMode::Other(7));
+        bond.xmit_hash_policy = XmitHashPolicy::Other(5);
+
+        let link = parse_link(&link_message(10, &attrs)).unwrap();
+        assert_eq!(parse_bond(&link), Some((bond, None, Some(4))));
+    }
+}
@@ -0,0 +1,332 @@
+use super::link::{change_link_request, get_link_by_name, link_info_attr, list_links};
+use super::netlink::{
+    as_bytes, attr_u32, attr_u8, read_struct, NetlinkSocket, NlAttrs, NlMessage, NlMsgBuilder,
+};
+use ifstructs::{
+    br_mdb_entry, br_port_msg, bridge_vlan_info, ifinfomsg, BRIDGE_FLAGS_SELF,
+    BRIDGE_VLAN_INFO_PVID, BRIDGE_VLAN_INFO_UNTAGGED, IFLA_AF_SPEC, IFLA_BRIDGE_FLAGS,
+    IFLA_BRIDGE_VLAN_INFO, IFLA_BR_AGEING_TIME, IFLA_BR_FORWARD_DELAY, IFLA_BR_STP_STATE,
+    IFLA_BR_VLAN_FILTERING, IFLA_EXT_MASK, MDBA_MDB, MDBA_MDB_ENTRY, MDBA_MDB_ENTRY_INFO,
+    MDBA_SET_ENTRY, MDB_PERMANENT, MDB_TEMPORARY, NLM_F_CREATE, NLM_F_EXCL, RTEXT_FILTER_BRVLAN,
+    RTM_DELLINK, RTM_DELMDB, RTM_GETLINK, RTM_GETMDB, RTM_NEWLINK, RTM_NEWMDB, RTM_SETLINK,
+};
+use libc;
+use std::collections::HashMap;
+use std::io;
+use std::mem;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+use {BridgeSettings, BridgeVlan, IfError, MdbEntry};
+
+/// Bridge timers are in `USER_HZ` (1/100 s) units
+fn to_clock_t(d: Duration) -> Result<u32, IfError> {
+    d.as_secs()
+        .checked_mul(100)
+        .and_then(|v| v.checked_add(u64::from(d.subsec_nanos() / 10_000_000)))
+        .filter(|v| *v <= u64::from(u32::MAX))
+        .map(|v| v as u32)
+        .ok_or_else(|| {
+            IfError::from(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "bridge timer is out of range",
+            ))
+        })
+}
+
+fn from_clock_t(v: u32) -> Duration {
+    Duration::from_millis(u64::from(v) * 10)
+}
+
+/// Sets `IFLA_BR_*` attributes of the bridge, added by `f`
+fn set_bridge_attrs<F: FnOnce(&mut NlMsgBuilder)>(
+    nl: &mut NetlinkSocket,
+    bridge: &str,
+    f: F,
+) -> Result<(), IfError> {
+    let link = get_link_by_name(nl, bridge)?;
+    let mut req = change_link_request(link.index);
+    link_info_attr(&mut req, "bridge", f);
+    nl.request(req)
+}
+
+pub fn set_bridge_stp(nl: &mut NetlinkSocket, bridge: &str, enable: bool) -> Result<(), IfError> {
+    set_bridge_attrs(nl, bridge, |req| {
+        req.attr_u32(IFLA_BR_STP_STATE, enable as u32);
+    })
+}
+
+pub fn set_bridge_ageing_time(
+    nl: &mut NetlinkSocket,
+    bridge: &str,
+    time: Duration,
+) -> Result<(), IfError> {
+    let v = to_clock_t(time)?;
+    set_bridge_attrs(nl, bridge, |req| {
+        req.attr_u32(IFLA_BR_AGEING_TIME, v);
+    })
+}
+
+pub fn set_bridge_forward_delay(
+    nl: &mut NetlinkSocket,
+    bridge: &str,
+    delay: Duration,
+) -> Result<(), IfError> {
+    let v = to_clock_t(delay)?;
+    set_bridge_attrs(nl, bridge, |req| {
+        req.attr_u32(IFLA_BR_FORWARD_DELAY, v);
+    })
+}
+
+pub fn set_bridge_vlan_filtering(
+    nl: &mut NetlinkSocket,
+    bridge: &str,
+    enable: bool,
+) -> Result<(), IfError> {
+    set_bridge_attrs(nl, bridge, |req| {
+        req.attr_u8(IFLA_BR_VLAN_FILTERING, enable as u8);
+    })
+}
+
+pub fn get_bridge_settings(
+    nl: &mut NetlinkSocket,
+    bridge: &str,
+) -> Result<BridgeSettings, IfError> {
+    let link = get_link_by_name(nl, bridge)?;
+    if link.kind.as_deref() != Some("bridge") {
+        return Err(IfError::NotFound);
+    }
+    let mut settings = BridgeSettings {
+        stp: false,
+        ageing_time: Duration::from_secs(0),
+        forward_delay: Duration::from_secs(0),
+        vlan_filtering: false,
+    };
+    for (ty, data) in NlAttrs::new(&link.info_data) {
+        match ty {
+            IFLA_BR_STP_STATE => settings.stp = attr_u32(data).unwrap_or(0) != 0,
+            IFLA_BR_AGEING_TIME => settings.ageing_time = from_clock_t(attr_u32(data).unwrap_or(0)),
+            IFLA_BR_FORWARD_DELAY => {
+                settings.forward_delay = from_clock_t(attr_u32(data).unwrap_or(0))
+            }
+            IFLA_BR_VLAN_FILTERING => settings.vlan_filtering = attr_u8(data).unwrap_or(0) != 0,
+            _ => {}
+        }
+    }
+    Ok(settings)
+}
+
+/// Names of the interfaces, enslaved to the bridge
+pub fn list_bridge_ports(nl: &mut NetlinkSocket, bridge: &str) -> Result<Vec<String>, IfError> {
+    let bridge = get_link_by_name(nl, bridge)?;
+    Ok(list_links(nl)?
+        .into_iter()
+        .filter(|l| l.master == Some(bridge.index))
+        .map(|l| l.name)
+        .collect())
+}
+
+/// `AF_BRIDGE` link request. VLANs of the bridge itself are addressed with `BRIDGE_FLAGS_SELF`
+fn bridge_vlan_request(
+    nl: &mut NetlinkSocket,
+    ty: u16,
+    bridge: &str,
+    port: &str,
+    info: bridge_vlan_info,
+) -> Result<NlMsgBuilder, IfError> {
+    let mut ifi: ifinfomsg = unsafe { mem::zeroed() };
+    ifi.ifi_family = libc::AF_BRIDGE as u8;
+    ifi.ifi_index = get_link_by_name(nl, port)?.index;
+
+    let mut req = NlMsgBuilder::new(ty, 0, &ifi);
+    let af_spec = req.begin_nested(IFLA_AF_SPEC);
+    if port == bridge {
+        req.attr_u16(IFLA_BRIDGE_FLAGS, BRIDGE_FLAGS_SELF);
+    }
+    req.attr(IFLA_BRIDGE_VLAN_INFO, unsafe { as_bytes(&info) });
+    req.end_nested(af_spec);
+    Ok(req)
+}
+
+pub fn add_bridge_vlan(
+    nl: &mut NetlinkSocket,
+    bridge: &str,
+    port: &str,
+    vlan: &BridgeVlan,
+) -> Result<(), IfError> {
+    let mut flags = 0;
+    if vlan.pvid {
+        flags |= BRIDGE_VLAN_INFO_PVID;
+    }
+    if vlan.untagged {
+        flags |= BRIDGE_VLAN_INFO_UNTAGGED;
+    }
+    let info = bridge_vlan_info {
+        flags,
+        vid: vlan.vid,
+    };
+    let req = bridge_vlan_request(nl, RTM_SETLINK, bridge, port, info)?;
+    nl.request(req)
+}
+
+pub fn del_bridge_vlan(
+    nl: &mut NetlinkSocket,
+    bridge: &str,
+    port: &str,
+    vid: u16,
+) -> Result<(), IfError> {
+    let info = bridge_vlan_info { flags: 0, vid };
+    let req = bridge_vlan_request(nl, RTM_DELLINK, bridge, port, info)?;
+    nl.request(req)
+}
+
+fn parse_bridge_vlans(msg: &NlMessage) -> Option<(libc::c_int, Vec<BridgeVlan>)> {
+    if msg.ty != RTM_NEWLINK {
+        return None;
+    }
+    let ifi: ifinfomsg = msg.header()?;
+    if i32::from(ifi.ifi_family) != libc::AF_BRIDGE {
+        return None;
+    }
+    let mut vlans = vec![];
+    for (ty, data) in msg.attrs::<ifinfomsg>() {
+        if ty != IFLA_AF_SPEC {
+            continue;
+        }
+        for (ty, data) in NlAttrs::new(data) {
+            if ty != IFLA_BRIDGE_VLAN_INFO {
+                continue;
+            }
+            let info: bridge_vlan_info = read_struct(data)?;
+            vlans.push(BridgeVlan {
+                vid: info.vid,
+                pvid: info.flags & BRIDGE_VLAN_INFO_PVID != 0,
+                untagged: info.flags & BRIDGE_VLAN_INFO_UNTAGGED != 0,
+            });
+        }
+    }
+    Some((ifi.ifi_index, vlans))
+}
+
+/// VLANs of the bridge port or the bridge itself
+pub fn list_bridge_vlans(nl: &mut NetlinkSocket, port: &str) -> Result<Vec<BridgeVlan>, IfError> {
+    let index = get_link_by_name(nl, port)?.index;
+
+    let mut ifi: ifinfomsg = unsafe { mem::zeroed() };
+    ifi.ifi_family = libc::AF_BRIDGE as u8;
+    let mut req = NlMsgBuilder::new(RTM_GETLINK, 0, &ifi);
+    req.attr_u32(IFLA_EXT_MASK, RTEXT_FILTER_BRVLAN);
+    Ok(nl
+        .dump(req)?
+        .iter()
+        .filter_map(parse_bridge_vlans)
+        .filter(|&(i, _)| i == index)
+        .flat_map(|(_, vlans)| vlans)
+        .collect())
+}
+
+fn mdb_group(entry: &br_mdb_entry) -> Option<IpAddr> {
+    match i32::from(u16::from_be(entry.addr.proto)) {
+        libc::ETH_P_IP => Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(unsafe {
+            entry.addr.u.ip4
+        })))),
+        libc::ETH_P_IPV6 => Some(IpAddr::V6(Ipv6Addr::from(unsafe {
+            entry.addr.u.ip6.s6_addr
+        }))),
+        _ => None,
+    }
+}
+
+fn parse_mdb(msg: &NlMessage, names: &HashMap<libc::c_int, String>) -> Vec<MdbEntry> {
+    let mut entries = vec![];
+    let bpm: br_port_msg = match msg.header() {
+        Some(bpm) => bpm,
+        None => return entries,
+    };
+    let bridge = match names.get(&(bpm.ifindex as libc::c_int)) {
+        Some(bridge) => bridge,
+        None => return entries,
+    };
+    for (ty, data) in msg.attrs::<br_port_msg>() {
+        if ty != MDBA_MDB {
+            continue;
+        }
+        let infos = NlAttrs::new(data)
+            .filter(|&(ty, _)| ty == MDBA_MDB_ENTRY)
+            .flat_map(|(_, data)| NlAttrs::new(data))
+            .filter(|&(ty, _)| ty == MDBA_MDB_ENTRY_INFO);
+        for (_, data) in infos {
+            let info: br_mdb_entry = match read_struct(data) {
+                Some(info) => info,
+                None => continue,
+            };
+            let port = names.get(&(info.ifindex as libc::c_int));
+            if let (Some(port), Some(group)) = (port, mdb_group(&info)) {
+                let mut entry = MdbEntry::new(bridge, port, group);
+                entry.vid = if info.vid != 0 { Some(info.vid) } else { None };
+                entry.permanent = info.state == MDB_PERMANENT;
+                entries.push(entry);
+            }
+        }
+    }
+    entries
+}
+
+pub fn list_mdb(nl: &mut NetlinkSocket, bridge: &str) -> Result<Vec<MdbEntry>, IfError> {
+    let names = list_links(nl)?
+        .into_iter()
+        .map(|l| (l.index, l.name))
+        .collect();
+
+    let mut bpm: br_port_msg = unsafe { mem::zeroed() };
+    bpm.family = libc::AF_BRIDGE as u8;
+    Ok(nl
+        .dump(NlMsgBuilder::new(RTM_GETMDB, 0, &bpm))?
+        .iter()
+        .flat_map(|msg| parse_mdb(msg, &names))
+        .filter(|e| e.bridge == bridge)
+        .collect())
+}
+
+fn mdb_request(
+    nl: &mut NetlinkSocket,
+    ty: u16,
+    flags: u16,
+    entry: &MdbEntry,
+) -> Result<NlMsgBuilder, IfError> {
+    let mut bpm: br_port_msg = unsafe { mem::zeroed() };
+    bpm.family = libc::AF_BRIDGE as u8;
+    bpm.ifindex = get_link_by_name(nl, &entry.bridge)?.index as u32;
+
+    let mut info: br_mdb_entry = unsafe { mem::zeroed() };
+    info.ifindex = get_link_by_name(nl, &entry.port)?.index as u32;
+    info.state = if entry.permanent {
+        MDB_PERMANENT
+    } else {
+        MDB_TEMPORARY
+    };
+    info.vid = entry.vid.unwrap_or(0);
+    match entry.group {
+        IpAddr::V4(ip) => {
+            info.addr.u.ip4 = u32::from(ip).to_be();
+            info.addr.proto = (libc::ETH_P_IP as u16).to_be();
+        }
+        IpAddr::V6(ip) => {
+            info.addr.u.ip6.s6_addr = ip.octets();
+            info.addr.proto = (libc::ETH_P_IPV6 as u16).to_be();
+        }
+    }
+
+    let mut req = NlMsgBuilder::new(ty, flags, &bpm);
+    req.attr(MDBA_SET_ENTRY, unsafe { as_bytes(&info) });
+    Ok(req)
+}
+
+pub fn add_mdb(nl: &mut NetlinkSocket, entry: &MdbEntry) -> Result<(), IfError> {
+    let req = mdb_request(nl, RTM_NEWMDB, NLM_F_CREATE | NLM_F_EXCL, entry)?;
+    nl.request(req)
+}
+
+pub fn del_mdb(nl: &mut NetlinkSocket, entry: &MdbEntry) -> Result<(), IfError> {
+    let req = mdb_request(nl, RTM_DELMDB, 0, entry)?;
+    nl.request(req)
+}
@@ -0,0 +1,99 @@
+use super::link::{get_link_by_name, list_links};
+use super::netlink::{attr_ip, attr_u16, attr_u32, NetlinkSocket, NlMessage, NlMsgBuilder};
+use eui48::MacAddress;
+use ifstructs::{
+    ndmsg, NtfFlags, NudState, NDA_DST, NDA_LLADDR, NDA_MASTER, NDA_PORT, NDA_VLAN, NDA_VNI,
+    NLM_F_APPEND, NLM_F_CREATE, NLM_F_EXCL, RTM_DELNEIGH, RTM_GETNEIGH, RTM_NEWNEIGH,
+};
+use libc;
+use std::collections::HashMap;
+use std::mem;
+use {FdbEntry, IfError};
+
+fn parse_fdb(msg: &NlMessage, names: &HashMap<libc::c_int, String>) -> Option<FdbEntry> {
+    if msg.ty != RTM_NEWNEIGH {
+        return None;
+    }
+    let ndm: ndmsg = msg.header()?;
+    if i32::from(ndm.ndm_family) != libc::AF_BRIDGE {
+        return None;
+    }
+
+    let mut lladdr = None;
+    let mut entry = FdbEntry::new(names.get(&ndm.ndm_ifindex)?, MacAddress::nil());
+    entry.flags = NtfFlags::from_bits_truncate(ndm.ndm_flags);
+    for (ty, data) in msg.attrs::<ndmsg>() {
+        match ty {
+            NDA_LLADDR => lladdr = MacAddress::from_bytes(data).ok(),
+            NDA_DST => entry.dst = attr_ip(data),
+            NDA_VLAN => entry.vlan = attr_u16(data),
+            NDA_VNI => entry.vni = attr_u32(data),
+            NDA_PORT => entry.port = attr_u16(data).map(u16::from_be),
+            // bridge doesn't set the flag in the entries of its own database
+            NDA_MASTER => entry.flags |= NtfFlags::NTF_MASTER,
+            _ => {}
+        }
+    }
+    entry.lladdr = lladdr?;
+    entry.state = NudState::from_bits_truncate(ndm.ndm_state);
+    Some(entry)
+}
+
+pub fn list_fdb(nl: &mut NetlinkSocket) -> Result<Vec<FdbEntry>, IfError> {
+    let names = list_links(nl)?
+        .into_iter()
+        .map(|l| (l.index, l.name))
+        .collect();
+
+    let mut ndm: ndmsg = unsafe { mem::zeroed() };
+    ndm.ndm_family = libc::AF_BRIDGE as u8;
+    Ok(nl
+        .dump(NlMsgBuilder::new(RTM_GETNEIGH, 0, &ndm))?
+        .iter()
+        .filter_map(|msg| parse_fdb(msg, &names))
+        .collect())
+}
+
+fn fdb_request(
+    nl: &mut NetlinkSocket,
+    ty: u16,
+    flags: u16,
+    entry: &FdbEntry,
+) -> Result<NlMsgBuilder, IfError> {
+    let mut ndm: ndmsg = unsafe { mem::zeroed() };
+    ndm.ndm_family = libc::AF_BRIDGE as u8;
+    ndm.ndm_ifindex = get_link_by_name(nl, &entry.iface)?.index;
+    ndm.ndm_state = entry.state.bits();
+    ndm.ndm_flags = entry.flags.bits();
+
+    let mut req = NlMsgBuilder::new(ty, flags, &ndm);
+    req.attr(NDA_LLADDR, entry.lladdr.as_bytes());
+    if let Some(dst) = entry.dst {
+        req.attr_ip(NDA_DST, dst);
+    }
+    if let Some(vlan) = entry.vlan {
+        req.attr_u16(NDA_VLAN, vlan);
+    }
+    if let Some(vni) = entry.vni {
+        req.attr_u32(NDA_VNI, vni);
+    }
+    if let Some(port) = entry.port {
+        req.attr_u16(NDA_PORT, port.to_be());
+    }
+    Ok(req)
+}
+
+pub fn add_fdb(nl: &mut NetlinkSocket, entry: &FdbEntry) -> Result<(), IfError> {
+    let req = fdb_request(nl, RTM_NEWNEIGH, NLM_F_CREATE | NLM_F_EXCL, entry)?;
+    nl.request(req)
+}
+
+pub fn append_fdb(nl: &mut NetlinkSocket, entry: &FdbEntry) -> Result<(), IfError> {
+    let req = fdb_request(nl, RTM_NEWNEIGH, NLM_F_CREATE | NLM_F_APPEND, entry)?;
+    nl.request(req)
+}
+
+pub fn del_fdb(nl: &mut NetlinkSocket, entry: &FdbEntry) -> Result<(), IfError> {
+    let req = fdb_request(nl, RTM_DELNEIGH, 0, entry)?;
+    nl.request(req)
+}
@@ -0,0 +1,286 @@
+use super::netlink::{
+    attr_string, attr_u32, attr_u8, read_struct, NetlinkSocket, NlAttrs, NlMessage, NlMsgBuilder,
+};
+use eui48::MacAddress;
+use ifstructs::{
+    ifinfomsg, rtnl_link_stats64, IfFlags, IFLA_ADDRESS, IFLA_IFNAME, IFLA_INFO_DATA,
+    IFLA_INFO_KIND, IFLA_INFO_SLAVE_DATA, IFLA_INFO_SLAVE_KIND, IFLA_LINK, IFLA_LINKINFO,
+    IFLA_LINK_NETNSID, IFLA_MASTER, IFLA_MTU, IFLA_NET_NS_FD, IFLA_OPERSTATE, IFLA_QDISC,
+    IFLA_STATS64, IFLA_TXQLEN, IF_OPER_UNKNOWN, NLM_F_CREATE, NLM_F_EXCL, RTM_DELLINK, RTM_GETLINK,
+    RTM_NEWLINK,
+};
+use libc;
+use std::{cmp, mem};
+use {IfError, LinkOptions};
+
+/// Interface, as reported by `RTM_NEWLINK` or `RTM_DELLINK`
+#[derive(Debug, Clone)]
+pub struct LinkRecord {
+    pub index: libc::c_int,
+    pub name: String,
+    pub flags: IfFlags,
+    /// `ARPHRD_*` hardware type
+    pub link_type: u16,
+    /// `IF_OPER_*` operational state
+    pub operstate: u8,
+    pub hw_addr: Option<MacAddress>,
+    pub mtu: u32,
+    pub txqueuelen: u32,
+    pub qdisc: Option<String>,
+    pub stats: Option<rtnl_link_stats64>,
+    /// Parent (vlan) or peer (veth) interface index
+    pub parent: Option<libc::c_int>,
+    /// `IFLA_LINK_NETNSID`, if the parent is in another network namespace
+    pub link_netnsid: Option<i32>,
+    /// Bridge or bond, this interface is enslaved to
+    pub master: Option<libc::c_int>,
+    /// `IFLA_INFO_KIND` of virtual interfaces
+    pub kind: Option<String>,
+    /// Kind-specific `IFLA_INFO_DATA` attributes
+    pub info_data: Vec<u8>,
+    /// `IFLA_INFO_SLAVE_KIND` of the enslaved interfaces, i.e. the kind of the master
+    pub slave_kind: Option<String>,
+    /// Master-specific `IFLA_INFO_SLAVE_DATA` attributes
+    pub slave_info_data: Vec<u8>,
+}
+
+impl LinkRecord {
+    /// Parent index, if the parent is in the same network namespace
+    pub fn local_parent(&self) -> Option<libc::c_int> {
+        self.parent.filter(|_| self.link_netnsid.is_none())
+    }
+}
+
+/// Older kernels report shorter `rtnl_link_stats64`, missing fields are left zeroed
+fn parse_stats64(data: &[u8]) -> Option<rtnl_link_stats64> {
+    let mut buf = [0u8; mem::size_of::<rtnl_link_stats64>()];
+    let len = cmp::min(data.len(), buf.len());
+    buf[..len].copy_from_slice(&data[..len]);
+    read_struct(&buf)
+}
+
+pub fn parse_link(msg: &NlMessage) -> Option<LinkRecord> {
+    if msg.ty != RTM_NEWLINK && msg.ty != RTM_DELLINK {
+        return None;
+    }
+    let ifi: ifinfomsg = msg.header()?;
+    // bridge ports are reported once more with AF_BRIDGE
+    if i32::from(ifi.ifi_family) != libc::AF_UNSPEC {
+        return None;
+    }
+    let mut name = None;
+    let mut hw_addr = None;
+    let mut mtu = 0;
+    let mut operstate = IF_OPER_UNKNOWN;
+    let mut txqueuelen = 0;
+    let mut qdisc = None;
+    let mut stats = None;
+    let mut parent = None;
+    let mut link_netnsid = None;
+    let mut master = None;
+    let mut kind = None;
+    let mut info_data = vec![];
+    let mut slave_kind = None;
+    let mut slave_info_data = vec![];
+    for (ty, data) in msg.attrs::<ifinfomsg>() {
+        match ty {
+            IFLA_IFNAME => name = attr_string(data),
+            IFLA_ADDRESS if data.len() == 6 => {
+                let addr = MacAddress::from_bytes(data).ok()?;
+                if !addr.is_nil() {
+                    hw_addr = Some(addr);
+                }
+            }
+            IFLA_MTU => mtu = attr_u32(data)?,
+            IFLA_OPERSTATE => operstate = attr_u8(data)?,
+            IFLA_TXQLEN => txqueuelen = attr_u32(data)?,
+            IFLA_QDISC => qdisc = attr_string(data),
+            IFLA_STATS64 => stats = parse_stats64(data),
+            IFLA_LINK => parent = attr_u32(data).map(|i| i as libc::c_int),
+            IFLA_LINK_NETNSID => link_netnsid = attr_u32(data).map(|i| i as i32),
+            IFLA_MASTER => master = attr_u32(data).map(|i| i as libc::c_int),
+            IFLA_LINKINFO => {
+                for (ty, data) in NlAttrs::new(data) {
+                    match ty {
+                        IFLA_INFO_KIND => kind = attr_string(data),
+                        IFLA_INFO_DATA => info_data = data.to_vec(),
+                        IFLA_INFO_SLAVE_KIND => slave_kind = attr_string(data),
+                        IFLA_INFO_SLAVE_DATA => slave_info_data = data.to_vec(),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(LinkRecord {
+        index: ifi.ifi_index,
+        name: name?,
+        flags: IfFlags::from_bits_truncate(ifi.ifi_flags as libc::c_int),
+        link_type: ifi.ifi_type,
+        operstate,
+        hw_addr,
+        mtu,
+        txqueuelen,
+        qdisc,
+        stats,
+        parent,
+        link_netnsid,
+        master,
+        kind,
+        info_data,
+        slave_kind,
+        slave_info_data,
+    })
+}
+
+fn link_request(ty: u16, flags: u16, index: libc::c_int) -> NlMsgBuilder {
+    let mut ifi: ifinfomsg = unsafe { mem::zeroed() };
+    ifi.ifi_family = libc::AF_UNSPEC as u8;
+    ifi.ifi_index = index;
+    NlMsgBuilder::new(ty, flags, &ifi)
+}
+
+/// `RTM_NEWLINK` request, creating new interface. Kind-specific attributes are added by the caller
+pub fn new_link_request(ifname: &str) -> NlMsgBuilder {
+    let mut req = link_request(RTM_NEWLINK, NLM_F_CREATE | NLM_F_EXCL, 0);
+    req.attr_str(IFLA_IFNAME, ifname);
+    req
+}
+
+/// `RTM_NEWLINK` request, changing settings of the existing interface
+pub fn change_link_request(index: libc::c_int) -> NlMsgBuilder {
+    link_request(RTM_NEWLINK, 0, index)
+}
+
+/// Appends `IFLA_LINKINFO` of the `kind`. `IFLA_INFO_DATA` is filled by `info_data`
+pub fn link_info_attr<F: FnOnce(&mut NlMsgBuilder)>(
+    req: &mut NlMsgBuilder,
+    kind: &str,
+    info_data: F,
+) {
+    let link_info = req.begin_nested(IFLA_LINKINFO);
+    req.attr_str(IFLA_INFO_KIND, kind);
+    let data = req.begin_nested(IFLA_INFO_DATA);
+    info_data(req);
+    req.end_nested(data);
+    req.end_nested(link_info);
+}
+
+/// Creates interface of the `kind`, which doesn't need kind-specific attributes
+pub fn create_link(
+    nl: &mut NetlinkSocket,
+    ifname: &str,
+    kind: &str,
+    opts: &LinkOptions,
+) -> Result<(), IfError> {
+    let mut req = new_link_request(ifname);
+    if let Some(mtu) = opts.mtu {
+        req.attr_u32(IFLA_MTU, mtu);
+    }
+    if let Some(hw_addr) = opts.hw_addr {
+        req.attr(IFLA_ADDRESS, hw_addr.as_bytes());
+    }
+    let link_info = req.begin_nested(IFLA_LINKINFO);
+    req.attr_str(IFLA_INFO_KIND, kind);
+    req.end_nested(link_info);
+    nl.request(req)
+}
+
+/// Enslaves the interface to the bridge or bond, or releases it with `None`
+pub fn set_link_master(
+    nl: &mut NetlinkSocket,
+    ifname: &str,
+    master: Option<&str>,
+) -> Result<(), IfError> {
+    let index = get_link_by_name(nl, ifname)?.index;
+    let master = match master {
+        Some(master) => get_link_by_name(nl, master)?.index,
+        None => 0,
+    };
+    let mut req = change_link_request(index);
+    req.attr_u32(IFLA_MASTER, master as u32);
+    nl.request(req)
+}
+
+/// Moves the interface into the network namespace, referred by the `netns` descriptor
+pub fn set_link_netns(
+    nl: &mut NetlinkSocket,
+    ifname: &str,
+    netns: libc::c_int,
+) -> Result<(), IfError> {
+    let index = get_link_by_name(nl, ifname)?.index;
+    let mut req = change_link_request(index);
+    req.attr_u32(IFLA_NET_NS_FD, netns as u32);
+    nl.request(req)
+}
+
+pub fn del_link(nl: &mut NetlinkSocket, index: libc::c_int) -> Result<(), IfError> {
+    nl.request(link_request(RTM_DELLINK, 0, index))
+}
+
+pub fn list_links(nl: &mut NetlinkSocket) -> Result<Vec<LinkRecord>, IfError> {
+    Ok(nl
+        .dump(link_request(RTM_GETLINK, 0, 0))?
+        .iter()
+        .filter_map(parse_link)
+        .collect())
+}
+
+pub fn get_link_by_name(nl: &mut NetlinkSocket, ifname: &str) -> Result<LinkRecord, IfError> {
+    let mut req = link_request(RTM_GETLINK, 0, 0);
+    req.attr_str(IFLA_IFNAME, ifname);
+    parse_link(&nl.get(req)?).ok_or(IfError::NotFound)
+}
+
+pub fn get_link_by_index(
+    nl: &mut NetlinkSocket,
+    index: libc::c_int,
+) -> Result<LinkRecord, IfError> {
+    parse_link(&nl.get(link_request(RTM_GETLINK, 0, index))?).ok_or(IfError::NotFound)
+}
+
+/// Link with the name of its parent (`IFLA_LINK`)
+pub fn get_link_with_parent(
+    nl: &mut NetlinkSocket,
+    ifname: &str,
+) -> Result<(LinkRecord, String), IfError> {
+    let link = get_link_by_name(nl, ifname)?;
+    let parent = get_link_by_index(nl, link.local_parent().ok_or(IfError::NotFound)?)?;
+    Ok((link, parent.name))
+}
+
+/// Attributes of the request, to compare them with the UAPI layout in tests
+#[cfg(test)]
+pub fn link_request_attrs(req: NlMsgBuilder) -> Vec<u8> {
+    req.into_message().payload[mem::size_of::<ifinfomsg>()..].to_vec()
+}
+
+/// `RTM_NEWLINK` message with the `attrs`, as the kernel reports the interface
+#[cfg(test)]
+pub fn link_message(index: libc::c_int, attrs: &[u8]) -> NlMessage {
+    let mut ifi: ifinfomsg = unsafe { mem::zeroed() };
+    ifi.ifi_index = index;
+    let mut payload = unsafe { super::netlink::as_bytes(&ifi) }.to_vec();
+    payload.extend_from_slice(attrs);
+    NlMessage {
+        ty: RTM_NEWLINK,
+        flags: 0,
+        seq: 0,
+        payload,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_links() {
+        let mut nl = NetlinkSocket::new().unwrap();
+        let links = list_links(&mut nl).expect("list links");
+        assert!(links.iter().any(|l| l.name == "lo"));
+        let lo = get_link_by_name(&mut nl, "lo").expect("get lo");
+        assert!(lo.flags.contains(IfFlags::IFF_LOOPBACK));
+    }
+}
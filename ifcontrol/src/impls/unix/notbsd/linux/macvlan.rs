@@ -0,0 +1,200 @@
+use super::link::{
+    get_link_by_name, get_link_with_parent, link_info_attr, new_link_request, LinkRecord,
+};
+use super::netlink::{attr_u16, attr_u32, NetlinkSocket, NlAttrs, NlMsgBuilder};
+use ifstructs::{
+    IFLA_ADDRESS, IFLA_IPVLAN_MODE, IFLA_LINK, IFLA_MACVLAN_MODE, IPVLAN_MODE_L2, IPVLAN_MODE_L3,
+    IPVLAN_MODE_L3S, MACVLAN_MODE_BRIDGE, MACVLAN_MODE_PASSTHRU, MACVLAN_MODE_PRIVATE,
+    MACVLAN_MODE_VEPA,
+};
+use libc;
+use {IfError, Ipvlan, IpvlanMode, Macvlan, MacvlanMode};
+
+fn macvlan_request(parent: libc::c_int, macvlan: &Macvlan) -> NlMsgBuilder {
+    let mut req = new_link_request(&macvlan.name);
+    req.attr_u32(IFLA_LINK, parent as u32);
+    if let Some(hw_addr) = macvlan.hw_addr {
+        req.attr(IFLA_ADDRESS, hw_addr.as_bytes());
+    }
+    link_info_attr(&mut req, "macvlan", |req| {
+        let mode = match macvlan.mode {
+            MacvlanMode::Private => MACVLAN_MODE_PRIVATE,
+            MacvlanMode::Vepa => MACVLAN_MODE_VEPA,
+            MacvlanMode::Bridge => MACVLAN_MODE_BRIDGE,
+            MacvlanMode::Passthru => MACVLAN_MODE_PASSTHRU,
+            MacvlanMode::Other(v) => v,
+        };
+        req.attr_u32(IFLA_MACVLAN_MODE, mode);
+    });
+    req
+}
+
+pub fn create_macvlan(nl: &mut NetlinkSocket, macvlan: &Macvlan) -> Result<(), IfError> {
+    let parent = get_link_by_name(nl, &macvlan.parent)?;
+    nl.request(macvlan_request(parent.index, macvlan))
+}
+
+/// Parses settings of the `link` with "macvlan" kind
+pub fn parse_macvlan(link: &LinkRecord, parent: &str) -> Option<Macvlan> {
+    if link.kind.as_deref() != Some("macvlan") {
+        return None;
+    }
+    // the kernel always reports the mode, vepa is its default
+    let mut mode = MacvlanMode::Vepa;
+    for (ty, data) in NlAttrs::new(&link.info_data) {
+        if ty == IFLA_MACVLAN_MODE {
+            mode = match attr_u32(data)? {
+                MACVLAN_MODE_PRIVATE => MacvlanMode::Private,
+                MACVLAN_MODE_VEPA => MacvlanMode::Vepa,
+                MACVLAN_MODE_BRIDGE => MacvlanMode::Bridge,
+                MACVLAN_MODE_PASSTHRU => MacvlanMode::Passthru,
+                v => MacvlanMode::Other(v),
+            };
+        }
+    }
+    let mut macvlan = Macvlan::new(&link.name, parent, mode);
+    macvlan.hw_addr = link.hw_addr;
+    Some(macvlan)
+}
+
+pub fn get_macvlan(nl: &mut NetlinkSocket, ifname: &str) -> Result<Macvlan, IfError> {
+    let (link, parent) = get_link_with_parent(nl, ifname)?;
+    parse_macvlan(&link, &parent).ok_or(IfError::NotFound)
+}
+
+fn ipvlan_request(parent: libc::c_int, ipvlan: &Ipvlan) -> NlMsgBuilder {
+    let mut req = new_link_request(&ipvlan.name);
+    req.attr_u32(IFLA_LINK, parent as u32);
+    link_info_attr(&mut req, "ipvlan", |req| {
+        let mode = match ipvlan.mode {
+            IpvlanMode::L2 => IPVLAN_MODE_L2,
+            IpvlanMode::L3 => IPVLAN_MODE_L3,
+            IpvlanMode::L3S => IPVLAN_MODE_L3S,
+            IpvlanMode::Other(v) => v,
+        };
+        req.attr_u16(IFLA_IPVLAN_MODE, mode);
+    });
+    req
+}
+
+pub fn create_ipvlan(nl: &mut NetlinkSocket, ipvlan: &Ipvlan) -> Result<(), IfError> {
+    let parent = get_link_by_name(nl, &ipvlan.parent)?;
+    nl.request(ipvlan_request(parent.index, ipvlan))
+}
+
+/// Parses settings of the `link` with "ipvlan" kind
+pub fn parse_ipvlan(link: &LinkRecord, parent: &str) -> Option<Ipvlan> {
+    if link.kind.as_deref() != Some("ipvlan") {
+        return None;
+    }
+    let mut mode = IpvlanMode::L3;
+    for (ty, data) in NlAttrs::new(&link.info_data) {
+        if ty == IFLA_IPVLAN_MODE {
+            mode = match attr_u16(data)? {
+                IPVLAN_MODE_L2 => IpvlanMode::L2,
+                IPVLAN_MODE_L3 => IpvlanMode::L3,
+                IPVLAN_MODE_L3S => IpvlanMode::L3S,
+                v => IpvlanMode::Other(v),
+            };
+        }
+    }
+    Some(Ipvlan::new(&link.name, parent, mode))
+}
+
+pub fn get_ipvlan(nl: &mut NetlinkSocket, ifname: &str) -> Result<Ipvlan, IfError> {
+    let (link, parent) = get_link_with_parent(nl, ifname)?;
+    parse_ipvlan(&link, &parent).ok_or(IfError::NotFound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::link::{link_message, link_request_attrs, parse_link};
+    use super::*;
+    use eui48::MacAddress;
+
+    #[test]
+    fn test_macvlan_request() {
+        let mut macvlan = Macvlan::new("macvlan0", "eth0", MacvlanMode::Passthru);
+        macvlan.hw_addr = Some(MacAddress::parse_str("02:00:00:00:14:01").unwrap());
+
+        let expected: Vec<u8> = [
+            // IFLA_IFNAME
+            &[13, 0, 3, 0][..],
+            b"macvlan0\0\0\0\0",
+            // IFLA_LINK
+            &[8, 0, 5, 0, 2, 0, 0, 0],
+            // IFLA_ADDRESS
+            &[10, 0, 1, 0, 2, 0, 0, 0, 0x14, 1, 0, 0],
+            // IFLA_LINKINFO, IFLA_INFO_KIND
+            &[28, 0, 18, 0x80, 12, 0, 1, 0],
+            b"macvlan\0",
+            // IFLA_INFO_DATA, IFLA_MACVLAN_MODE: MACVLAN_MODE_PASSTHRU
+            &[12, 0, 2, 0x80, 8, 0, 1, 0, 8, 0, 0, 0],
+        ]
+        .concat();
+        assert_eq!(link_request_attrs(macvlan_request(2, &macvlan)), expected);
+
+        let link = parse_link(&link_message(10, &expected)).expect("parse link");
+        assert_eq!(link.parent, Some(2));
+        assert_eq!(parse_macvlan(&link, "eth0"), Some(macvlan));
+        assert_eq!(parse_ipvlan(&link, "eth0"), None);
+    }
+
+    #[test]
+    fn test_ipvlan_request() {
+        let ipvlan = Ipvlan::new("ipvlan0", "eth0", IpvlanMode::L3S);
+
+        let expected: Vec<u8> = [
+            // IFLA_IFNAME
+            &[12, 0, 3, 0][..],
+            b"ipvlan0\0",
+            // IFLA_LINK
+            &[8, 0, 5, 0, 2, 0, 0, 0],
+            // IFLA_LINKINFO, IFLA_INFO_KIND
+            &[28, 0, 18, 0x80, 11, 0, 1, 0],
+            b"ipvlan\0\0",
+            // IFLA_INFO_DATA, IFLA_IPVLAN_MODE: IPVLAN_MODE_L3S
+            &[12, 0, 2, 0x80, 6, 0, 1, 0, 2, 0, 0, 0],
+        ]
+        .concat();
+        assert_eq!(link_request_attrs(ipvlan_request(2, &ipvlan)), expected);
+
+        let link = parse_link(&link_message(10, &expected)).expect("parse link");
+        assert_eq!(link.parent, Some(2));
+        assert_eq!(parse_ipvlan(&link, "eth0"), Some(ipvlan));
+    }
+
+    #[test]
+    fn test_unknown_modes() {
+        let macvlan: Vec<u8> = [
+            &[13, 0, 3, 0][..],
+            b"macvlan0\0\0\0\0",
+            &[8, 0, 5, 0, 2, 0, 0, 0],
+            &[28, 0, 18, 0, 12, 0, 1, 0],
+            b"macvlan\0",
+            // MACVLAN_MODE_SOURCE
+            &[12, 0, 2, 0, 8, 0, 1, 0, 16, 0, 0, 0],
+        ]
+        .concat();
+        let link = parse_link(&link_message(10, &macvlan)).unwrap();
+        assert_eq!(
+            parse_macvlan(&link, "eth0"),
+            Some(Macvlan::new("macvlan0", "eth0", MacvlanMode::Other(16)))
+        );
+
+        let ipvlan: Vec<u8> = [
+            &[12, 0, 3, 0][..],
+            b"ipvlan0\0",
+            &[8, 0, 5, 0, 2, 0, 0, 0],
+            &[28, 0, 18, 0, 11, 0, 1, 0],
+            b"ipvlan\0\0",
+            &[12, 0, 2, 0, 6, 0, 1, 0, 3, 0, 0, 0],
+        ]
+        .concat();
+        let link = parse_link(&link_message(10, &ipvlan)).unwrap();
+        assert_eq!(
+            parse_ipvlan(&link, "eth0"),
+            Some(Ipvlan::new("ipvlan0", "eth0", IpvlanMode::Other(3)))
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use super::link::{get_link_by_name, list_links};
+use super::netlink::{attr_ip, NetlinkSocket, NlMessage, NlMsgBuilder};
+use eui48::MacAddress;
+use ifstructs::{
+    ndmsg, NtfFlags, NudState, NDA_DST, NDA_LLADDR, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REPLACE,
+    RTM_DELNEIGH, RTM_GETNEIGH, RTM_NEWNEIGH,
+};
+use libc;
+use std::collections::HashMap;
+use std::mem;
+use std::net::IpAddr;
+use {IfError, Neighbor};
+
+fn parse_neigh(msg: &NlMessage, names: &HashMap<libc::c_int, String>) -> Option<Neighbor> {
+    if msg.ty != RTM_NEWNEIGH {
+        return None;
+    }
+    let ndm: ndmsg = msg.header()?;
+    match i32::from(ndm.ndm_family) {
+        libc::AF_INET | libc::AF_INET6 => {}
+        _ => return None,
+    }
+
+    let mut ip = None;
+    let mut lladdr = None;
+    for (ty, data) in msg.attrs::<ndmsg>() {
+        match ty {
+            NDA_DST => ip = attr_ip(data),
+            NDA_LLADDR if data.len() == 6 => lladdr = MacAddress::from_bytes(data).ok(),
+            _ => {}
+        }
+    }
+    Some(Neighbor {
+        iface: names.get(&ndm.ndm_ifindex)?.clone(),
+        ip: ip?,
+        lladdr,
+        state: NudState::from_bits_truncate(ndm.ndm_state),
+        flags: NtfFlags::from_bits_truncate(ndm.ndm_flags),
+    })
+}
+
+pub fn list_neighbors(nl: &mut NetlinkSocket) -> Result<Vec<Neighbor>, IfError> {
+    let names = list_links(nl)?
+        .into_iter()
+        .map(|l| (l.index, l.name))
+        .collect();
+
+    let mut neighbors = vec![];
+    // proxy entries are dumped only on explicit request
+    for flags in &[NtfFlags::empty(), NtfFlags::NTF_PROXY] {
+        let mut ndm: ndmsg = unsafe { mem::zeroed() };
+        ndm.ndm_family = libc::AF_UNSPEC as u8;
+        ndm.ndm_flags = flags.bits();
+        neighbors.extend(
+            nl.dump(NlMsgBuilder::new(RTM_GETNEIGH, 0, &ndm))?
+                .iter()
+                .filter_map(|msg| parse_neigh(msg, &names)),
+        );
+    }
+    Ok(neighbors)
+}
+
+fn neigh_request(
+    nl: &mut NetlinkSocket,
+    ty: u16,
+    flags: u16,
+    neigh: &Neighbor,
+) -> Result<NlMsgBuilder, IfError> {
+    let mut ndm: ndmsg = unsafe { mem::zeroed() };
+    ndm.ndm_family = match neigh.ip {
+        IpAddr::V4(_) => libc::AF_INET as u8,
+        IpAddr::V6(_) => libc::AF_INET6 as u8,
+    };
+    ndm.ndm_ifindex = get_link_by_name(nl, &neigh.iface)?.index;
+    ndm.ndm_state = neigh.state.bits();
+    ndm.ndm_flags = neigh.flags.bits();
+
+    let mut req = NlMsgBuilder::new(ty, flags, &ndm);
+    req.attr_ip(NDA_DST, neigh.ip);
+    if let Some(lladdr) = neigh.lladdr {
+        req.attr(NDA_LLADDR, lladdr.as_bytes());
+    }
+    Ok(req)
+}
+
+pub fn add_neighbor(nl: &mut NetlinkSocket, neigh: &Neighbor) -> Result<(), IfError> {
+    let req = neigh_request(nl, RTM_NEWNEIGH, NLM_F_CREATE | NLM_F_EXCL, neigh)?;
+    nl.request(req)
+}
+
+pub fn replace_neighbor(nl: &mut NetlinkSocket, neigh: &Neighbor) -> Result<(), IfError> {
+    let req = neigh_request(nl, RTM_NEWNEIGH, NLM_F_CREATE | NLM_F_REPLACE, neigh)?;
+    nl.request(req)
+}
+
+pub fn del_neighbor(nl: &mut NetlinkSocket, neigh: &Neighbor) -> Result<(), IfError> {
+    let req = neigh_request(nl, RTM_DELNEIGH, 0, neigh)?;
+    nl.request(req)
+}
@@ -0,0 +1,385 @@
+use ifstructs::{
+    nlmsg_align, nlmsgerr, nlmsghdr, rta_align, rtattr, NLA_F_NESTED, NLA_TYPE_MASK, NLMSG_DONE,
+    NLMSG_ERROR, NLMSG_NOOP, NLM_F_ACK, NLM_F_DUMP, NLM_F_MULTI, NLM_F_REQUEST,
+};
+use libc;
+use mio;
+use mio::event::Evented;
+use mio::unix::EventedFd;
+use nix;
+use nix::errno::Errno;
+use nix::sys::socket::{
+    bind, recv, send, socket, AddressFamily, MsgFlags, SockAddr, SockFlag, SockType,
+};
+use std::fs::File;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
+use std::{mem, ptr, slice};
+use IfError;
+
+const RECV_BUF_SIZE: usize = 65536;
+
+/// Converts negative errno, reported by the kernel in `NLMSG_ERROR`, to `IfError`
+pub fn nl_error(errno: libc::c_int) -> IfError {
+    match Errno::from_i32(errno.abs()) {
+        Errno::ENODEV => IfError::NotFound,
+        e => IfError::Nix(nix::Error::Sys(e)),
+    }
+}
+
+pub unsafe fn as_bytes<T: Copy>(v: &T) -> &[u8] {
+    slice::from_raw_parts(v as *const T as *const u8, mem::size_of::<T>())
+}
+
+/// Reads fixed-size structure from the beginning of `buf`
+pub fn read_struct<T: Copy>(buf: &[u8]) -> Option<T> {
+    if buf.len() < mem::size_of::<T>() {
+        return None;
+    }
+    Some(unsafe { ptr::read_unaligned(buf.as_ptr() as *const T) })
+}
+
+/// Rtnetlink request under construction: header, family-specific message and attributes
+pub struct NlMsgBuilder {
+    ty: u16,
+    flags: u16,
+    buf: Vec<u8>,
+}
+
+impl NlMsgBuilder {
+    pub fn new<T: Copy>(ty: u16, flags: u16, hdr: &T) -> NlMsgBuilder {
+        let mut buf = vec![0u8; nlmsg_align(mem::size_of::<nlmsghdr>())];
+        buf.extend_from_slice(unsafe { as_bytes(hdr) });
+        let aligned = nlmsg_align(buf.len());
+        buf.resize(aligned, 0);
+        NlMsgBuilder { ty, flags, buf }
+    }
+
+    pub fn attr(&mut self, ty: u16, data: &[u8]) -> &mut Self {
+        let hdr = rtattr {
+            rta_len: (mem::size_of::<rtattr>() + data.len()) as u16,
+            rta_type: ty,
+        };
+        self.buf.extend_from_slice(unsafe { as_bytes(&hdr) });
+        self.buf.extend_from_slice(data);
+        let aligned = rta_align(self.buf.len());
+        self.buf.resize(aligned, 0);
+        self
+    }
+
+    pub fn attr_u8(&mut self, ty: u16, v: u8) -> &mut Self {
+        self.attr(ty, &[v])
+    }
+
+    pub fn attr_u16(&mut self, ty: u16, v: u16) -> &mut Self {
+        self.attr(ty, unsafe { as_bytes(&v) })
+    }
+
+    pub fn attr_u32(&mut self, ty: u16, v: u32) -> &mut Self {
+        self.attr(ty, unsafe { as_bytes(&v) })
+    }
+
+    pub fn attr_str(&mut self, ty: u16, s: &str) -> &mut Self {
+        let mut data = s.as_bytes().to_vec();
+        data.push(0);
+        self.attr(ty, &data)
+    }
+
+    pub fn attr_ip(&mut self, ty: u16, ip: IpAddr) -> &mut Self {
+        match ip {
+            IpAddr::V4(ip) => self.attr(ty, &ip.octets()),
+            IpAddr::V6(ip) => self.attr(ty, &ip.octets()),
+        }
+    }
+
+    /// Appends fixed-size structure, e.g. `ifinfomsg` of `VETH_INFO_PEER`
+    pub fn append<T: Copy>(&mut self, v: &T) -> &mut Self {
+        self.buf.extend_from_slice(unsafe { as_bytes(v) });
+        let aligned = nlmsg_align(self.buf.len());
+        self.buf.resize(aligned, 0);
+        self
+    }
+
+    /// Starts nested attribute. Returns offset, which should be passed to `end_nested`
+    pub fn begin_nested(&mut self, ty: u16) -> usize {
+        let start = self.buf.len();
+        self.attr(ty | NLA_F_NESTED, &[]);
+        start
+    }
+
+    pub fn end_nested(&mut self, start: usize) -> &mut Self {
+        let len = (self.buf.len() - start) as u16;
+        self.buf[start..start + 2].copy_from_slice(unsafe { as_bytes(&len) });
+        self
+    }
+
+    /// Message, as if it was received from the kernel. Allows to test parsers against requests
+    #[cfg(test)]
+    pub fn into_message(self) -> NlMessage {
+        let (ty, flags) = (self.ty, self.flags);
+        let buf = self.finish(0);
+        NlMessage {
+            ty,
+            flags,
+            seq: 0,
+            payload: buf[nlmsg_align(mem::size_of::<nlmsghdr>())..].to_vec(),
+        }
+    }
+
+    fn finish(mut self, seq: u32) -> Vec<u8> {
+        let hdr = nlmsghdr {
+            nlmsg_len: self.buf.len() as u32,
+            nlmsg_type: self.ty,
+            nlmsg_flags: self.flags,
+            nlmsg_seq: seq,
+            nlmsg_pid: 0,
+        };
+        self.buf[..mem::size_of::<nlmsghdr>()].copy_from_slice(unsafe { as_bytes(&hdr) });
+        self.buf
+    }
+}
+
+/// Single message received from the kernel
+#[derive(Debug, Clone)]
+pub struct NlMessage {
+    pub ty: u16,
+    pub flags: u16,
+    pub seq: u32,
+    pub payload: Vec<u8>,
+}
+
+impl NlMessage {
+    /// Family-specific header (`ifinfomsg`, `ifaddrmsg`, ...)
+    pub fn header<T: Copy>(&self) -> Option<T> {
+        read_struct(&self.payload)
+    }
+
+    /// Attributes following the family-specific header `T`
+    pub fn attrs<T: Copy>(&self) -> NlAttrs<'_> {
+        let offset = nlmsg_align(mem::size_of::<T>());
+        if offset > self.payload.len() {
+            return NlAttrs { buf: &[] };
+        }
+        NlAttrs {
+            buf: &self.payload[offset..],
+        }
+    }
+}
+
+/// Iterator over `(type, payload)` pairs of netlink attributes
+pub struct NlAttrs<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> NlAttrs<'a> {
+    pub fn new(buf: &'a [u8]) -> NlAttrs<'a> {
+        NlAttrs { buf }
+    }
+}
+
+impl<'a> Iterator for NlAttrs<'a> {
+    type Item = (u16, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let hdr: rtattr = read_struct(self.buf)?;
+        let len = hdr.rta_len as usize;
+        if len < mem::size_of::<rtattr>() || len > self.buf.len() {
+            return None;
+        }
+        let data = &self.buf[mem::size_of::<rtattr>()..len];
+        self.buf = &self.buf[rta_align(len).min(self.buf.len())..];
+        Some((hdr.rta_type & NLA_TYPE_MASK, data))
+    }
+}
+
+pub fn attr_u8(data: &[u8]) -> Option<u8> {
+    data.first().cloned()
+}
+
+pub fn attr_u16(data: &[u8]) -> Option<u16> {
+    read_struct(data)
+}
+
+pub fn attr_u32(data: &[u8]) -> Option<u32> {
+    read_struct(data)
+}
+
+pub fn attr_string(data: &[u8]) -> Option<String> {
+    let end = data.iter().position(|x| *x == 0).unwrap_or(data.len());
+    String::from_utf8(data[..end].to_vec()).ok()
+}
+
+pub fn attr_ip(data: &[u8]) -> Option<IpAddr> {
+    match data.len() {
+        4 => {
+            let mut octets = [0u8; 4];
+            octets.copy_from_slice(data);
+            Some(IpAddr::V4(Ipv4Addr::from(octets)))
+        }
+        16 => {
+            let mut octets = [0u8; 16];
+            octets.copy_from_slice(data);
+            Some(IpAddr::V6(Ipv6Addr::from(octets)))
+        }
+        _ => None,
+    }
+}
+
+/// `NETLINK_ROUTE` socket
+pub struct NetlinkSocket {
+    inner: File,
+    seq: u32,
+}
+
+impl NetlinkSocket {
+    pub fn new() -> Result<NetlinkSocket, IfError> {
+        NetlinkSocket::with_groups(0)
+    }
+
+    /// Creates socket, subscribed to the multicast `groups` (`1 << (RTNLGRP_* - 1)` bitmask)
+    pub fn with_groups(groups: u32) -> Result<NetlinkSocket, IfError> {
+        let fd = socket(
+            AddressFamily::Netlink,
+            SockType::Raw,
+            SockFlag::SOCK_CLOEXEC,
+            None,
+        )?;
+        let inner = unsafe { File::from_raw_fd(fd) };
+        bind(fd, &SockAddr::new_netlink(0, groups))?;
+        Ok(NetlinkSocket { inner, seq: 0 })
+    }
+
+    fn send(&mut self, msg: NlMsgBuilder) -> Result<u32, IfError> {
+        self.seq = self.seq.wrapping_add(1);
+        let seq = self.seq;
+        let buf = msg.finish(seq);
+        send(self.inner.as_raw_fd(), &buf, MsgFlags::empty())?;
+        Ok(seq)
+    }
+
+    /// Receives all messages, contained in the next datagram
+    pub fn recv_messages(&mut self) -> Result<Vec<NlMessage>, IfError> {
+        self.recv_with_flags(MsgFlags::empty())
+    }
+
+    /// Same as `recv_messages`, but returns `None` instead of blocking, if there is no datagram
+    pub fn try_recv_messages(&mut self) -> Result<Option<Vec<NlMessage>>, IfError> {
+        match self.recv_with_flags(MsgFlags::MSG_DONTWAIT) {
+            Ok(messages) => Ok(Some(messages)),
+            Err(IfError::Nix(nix::Error::Sys(Errno::EAGAIN))) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn recv_with_flags(&mut self, flags: MsgFlags) -> Result<Vec<NlMessage>, IfError> {
+        let mut buf = vec![0u8; RECV_BUF_SIZE];
+        let len = recv(self.inner.as_raw_fd(), &mut buf, flags)?;
+        let hdr_len = mem::size_of::<nlmsghdr>();
+
+        let mut messages = vec![];
+        let mut i = 0;
+        while i + hdr_len <= len {
+            let hdr: nlmsghdr = read_struct(&buf[i..len]).unwrap();
+            let msg_len = hdr.nlmsg_len as usize;
+            if msg_len < hdr_len || i + msg_len > len {
+                break;
+            }
+            messages.push(NlMessage {
+                ty: hdr.nlmsg_type,
+                flags: hdr.nlmsg_flags,
+                seq: hdr.nlmsg_seq,
+                payload: buf[i + nlmsg_align(hdr_len)..i + msg_len].to_vec(),
+            });
+            i += nlmsg_align(msg_len);
+        }
+        Ok(messages)
+    }
+
+    /// Sends request and collects all replies until `NLMSG_DONE` or acknowledgement
+    fn transact(&mut self, msg: NlMsgBuilder) -> Result<Vec<NlMessage>, IfError> {
+        let seq = self.send(msg)?;
+        let mut replies = vec![];
+        loop {
+            for msg in self.recv_messages()? {
+                if msg.seq != seq {
+                    continue;
+                }
+                match msg.ty {
+                    NLMSG_NOOP => {}
+                    NLMSG_DONE => return Ok(replies),
+                    NLMSG_ERROR => {
+                        let err: nlmsgerr = msg.header().ok_or_else(|| {
+                            IfError::from(io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                "truncated netlink error",
+                            ))
+                        })?;
+                        if err.error != 0 {
+                            return Err(nl_error(err.error));
+                        }
+                        return Ok(replies);
+                    }
+                    _ => {
+                        let is_multi = msg.flags & NLM_F_MULTI != 0;
+                        replies.push(msg);
+                        if !is_multi {
+                            return Ok(replies);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Performs request, which doesn't return any data, waiting for the acknowledgement
+    pub fn request(&mut self, mut msg: NlMsgBuilder) -> Result<(), IfError> {
+        msg.flags |= NLM_F_REQUEST | NLM_F_ACK;
+        self.transact(msg)?;
+        Ok(())
+    }
+
+    /// Performs `NLM_F_DUMP` request
+    pub fn dump(&mut self, mut msg: NlMsgBuilder) -> Result<Vec<NlMessage>, IfError> {
+        msg.flags |= NLM_F_REQUEST | NLM_F_DUMP;
+        self.transact(msg)
+    }
+
+    /// Performs request, which returns single object
+    pub fn get(&mut self, mut msg: NlMsgBuilder) -> Result<NlMessage, IfError> {
+        msg.flags |= NLM_F_REQUEST;
+        self.transact(msg)?.pop().ok_or(IfError::NotFound)
+    }
+}
+
+impl AsRawFd for NetlinkSocket {
+    fn as_raw_fd(&self) -> RawFd {
+        self.inner.as_raw_fd()
+    }
+}
+
+impl Evented for NetlinkSocket {
+    fn register(
+        &self,
+        poll: &mio::Poll,
+        token: mio::Token,
+        interest: mio::Ready,
+        opts: mio::PollOpt,
+    ) -> io::Result<()> {
+        EventedFd(&self.as_raw_fd()).register(poll, token, interest, opts)
+    }
+
+    fn reregister(
+        &self,
+        poll: &mio::Poll,
+        token: mio::Token,
+        interest: mio::Ready,
+        opts: mio::PollOpt,
+    ) -> io::Result<()> {
+        EventedFd(&self.as_raw_fd()).reregister(poll, token, interest, opts)
+    }
+
+    fn deregister(&self, poll: &mio::Poll) -> io::Result<()> {
+        EventedFd(&self.as_raw_fd()).deregister(poll)
+    }
+}
@@ -0,0 +1,89 @@
+use nix::errno::Errno;
+use nix::mount::{mount, umount2, MntFlags, MsFlags};
+use nix::sched::{setns, unshare, CloneFlags};
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::thread;
+use IfError;
+
+const THREAD_NETNS: &str = "/proc/thread-self/ns/net";
+
+/// Runs `f` in a new network namespace. It is done in a separate thread, so the namespace
+/// of the calling one stays the same
+fn in_new_netns<T, F>(f: F) -> Result<T, IfError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, IfError> + Send + 'static,
+{
+    thread::spawn(move || {
+        unshare(CloneFlags::CLONE_NEWNET)?;
+        f()
+    })
+    .join()
+    .map_err(|_| IfError::from(io::Error::other("netns thread panicked")))?
+}
+
+/// New anonymous namespace. It lives as long as the returned file is open
+pub fn new_netns() -> Result<File, IfError> {
+    in_new_netns(|| Ok(File::open(THREAD_NETNS)?))
+}
+
+/// Makes the namespaces directory a shared mount point, so the namespaces bound
+/// there are visible from other mount namespaces. Same as iproute2 does
+fn share_netns_dir(dir: &Path) -> Result<(), IfError> {
+    fs::create_dir_all(dir)?;
+    let flags = MsFlags::MS_SHARED | MsFlags::MS_REC;
+    match mount(None::<&str>, dir, Some("none"), flags, None::<&str>) {
+        // not a mount point yet
+        Err(::nix::Error::Sys(Errno::EINVAL)) => {
+            let bind = MsFlags::MS_BIND | MsFlags::MS_REC;
+            mount(Some(dir), dir, Some("none"), bind, None::<&str>)?;
+            mount(None::<&str>, dir, Some("none"), flags, None::<&str>)?;
+            Ok(())
+        }
+        r => Ok(r?),
+    }
+}
+
+/// New namespace, which is kept alive by the bind mount at `path` until `del_named_netns`
+pub fn new_named_netns(path: &Path) -> Result<(), IfError> {
+    if let Some(dir) = path.parent() {
+        share_netns_dir(dir)?;
+    }
+    OpenOptions::new().write(true).create_new(true).open(path)?;
+
+    let target = path.to_owned();
+    let res = in_new_netns(move || {
+        mount(
+            Some(THREAD_NETNS),
+            &target,
+            Some("none"),
+            MsFlags::MS_BIND,
+            None::<&str>,
+        )?;
+        Ok(())
+    });
+    if res.is_err() {
+        let _ = fs::remove_file(path);
+    }
+    res
+}
+
+pub fn del_named_netns(path: &Path) -> Result<(), IfError> {
+    umount2(path, MntFlags::MNT_DETACH)?;
+    fs::remove_file(path)?;
+    Ok(())
+}
+
+/// Switches the calling thread into the namespace
+pub fn set_netns<F: AsRawFd>(netns: &F) -> Result<(), IfError> {
+    setns(netns.as_raw_fd(), CloneFlags::CLONE_NEWNET)?;
+    Ok(())
+}
+
+/// Namespace of the calling thread
+pub fn current_netns() -> Result<File, IfError> {
+    Ok(File::open(THREAD_NETNS)?)
+}
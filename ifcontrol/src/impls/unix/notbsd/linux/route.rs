@@ -0,0 +1,220 @@
+use super::link::{get_link_by_name, list_links};
+use super::netlink::{
+    as_bytes, attr_ip, attr_u32, read_struct, NetlinkSocket, NlAttrs, NlMessage, NlMsgBuilder,
+};
+use ifstructs::{
+    rta_align, rtattr, rtmsg, rtnexthop, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REPLACE, RTA_DST,
+    RTA_GATEWAY, RTA_MULTIPATH, RTA_OIF, RTA_PREFSRC, RTA_PRIORITY, RTA_TABLE, RTM_DELROUTE,
+    RTM_F_CLONED, RTM_GETROUTE, RTM_NEWROUTE, RT_SCOPE_HOST, RT_SCOPE_LINK, RT_SCOPE_NOWHERE,
+    RT_SCOPE_UNIVERSE, RT_TABLE_UNSPEC,
+};
+use ipnetwork::IpNetwork;
+use libc;
+use std::collections::HashMap;
+use std::io;
+use std::mem;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use {IfError, NextHop, Route, RouteType};
+
+/// Paths of `RTA_MULTIPATH`. `None`, if any of them is malformed
+fn parse_nexthops(data: &[u8], names: &HashMap<libc::c_int, String>) -> Option<Vec<NextHop>> {
+    let mut nexthops = vec![];
+    let mut buf = data;
+    while !buf.is_empty() {
+        let rtnh: rtnexthop = read_struct(buf)?;
+        let len = rtnh.rtnh_len as usize;
+        if len < mem::size_of::<rtnexthop>() || len > buf.len() {
+            return None;
+        }
+        let gateway = NlAttrs::new(&buf[mem::size_of::<rtnexthop>()..len])
+            .find(|&(ty, _)| ty == RTA_GATEWAY)
+            .and_then(|(_, data)| attr_ip(data));
+        nexthops.push(NextHop {
+            gateway,
+            iface: names.get(&rtnh.rtnh_ifindex).cloned(),
+            weight: u16::from(rtnh.rtnh_hops) + 1,
+        });
+        buf = &buf[rta_align(len).min(buf.len())..];
+    }
+    Some(nexthops)
+}
+
+/// Payload of `RTA_MULTIPATH`
+fn nexthops_attr(nl: &mut NetlinkSocket, nexthops: &[NextHop]) -> Result<Vec<u8>, IfError> {
+    let mut buf = vec![];
+    for nexthop in nexthops {
+        if nexthop.weight == 0 || nexthop.weight > 256 {
+            return Err(IfError::from(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "weight of the nexthop is out of range",
+            )));
+        }
+        let ifindex = match nexthop.iface {
+            Some(ref ifname) => get_link_by_name(nl, ifname)?.index,
+            None => 0,
+        };
+        let gateway = match nexthop.gateway {
+            Some(IpAddr::V4(ip)) => ip.octets().to_vec(),
+            Some(IpAddr::V6(ip)) => ip.octets().to_vec(),
+            None => vec![],
+        };
+        let attr_len = if gateway.is_empty() {
+            0
+        } else {
+            mem::size_of::<rtattr>() + gateway.len()
+        };
+        let rtnh = rtnexthop {
+            rtnh_len: (mem::size_of::<rtnexthop>() + attr_len) as u16,
+            rtnh_flags: 0,
+            rtnh_hops: (nexthop.weight - 1) as u8,
+            rtnh_ifindex: ifindex,
+        };
+        buf.extend_from_slice(unsafe { as_bytes(&rtnh) });
+        if !gateway.is_empty() {
+            let hdr = rtattr {
+                rta_len: attr_len as u16,
+                rta_type: RTA_GATEWAY,
+            };
+            // both kinds of addresses keep the 4-byte alignment
+            buf.extend_from_slice(unsafe { as_bytes(&hdr) });
+            buf.extend_from_slice(&gateway);
+        }
+    }
+    Ok(buf)
+}
+
+pub fn parse_route(msg: &NlMessage, names: &HashMap<libc::c_int, String>) -> Option<Route> {
+    if msg.ty != RTM_NEWROUTE && msg.ty != RTM_DELROUTE {
+        return None;
+    }
+    let rtm: rtmsg = msg.header()?;
+    if rtm.rtm_flags & RTM_F_CLONED != 0 {
+        return None;
+    }
+    let unspecified = match i32::from(rtm.rtm_family) {
+        libc::AF_INET => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        libc::AF_INET6 => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        _ => return None,
+    };
+
+    let mut route = Route::new(IpNetwork::new(unspecified, rtm.rtm_dst_len).ok()?);
+    route.table = u32::from(rtm.rtm_table);
+    route.protocol = rtm.rtm_protocol.into();
+    route.scope = Some(rtm.rtm_scope.into());
+    route.kind = rtm.rtm_type.into();
+
+    for (ty, data) in msg.attrs::<rtmsg>() {
+        match ty {
+            RTA_DST => route.destination = IpNetwork::new(attr_ip(data)?, rtm.rtm_dst_len).ok()?,
+            RTA_GATEWAY => route.gateway = attr_ip(data),
+            RTA_PREFSRC => route.source = attr_ip(data),
+            RTA_OIF => {
+                route.iface =
+                    attr_u32(data).and_then(|idx| names.get(&(idx as libc::c_int)).cloned())
+            }
+            RTA_PRIORITY => route.metric = attr_u32(data),
+            RTA_TABLE => route.table = attr_u32(data)?,
+            RTA_MULTIPATH => route.nexthops = parse_nexthops(data, names)?,
+            _ => {}
+        }
+    }
+    Some(route)
+}
+
+pub fn list_routes(nl: &mut NetlinkSocket) -> Result<Vec<Route>, IfError> {
+    let names = list_links(nl)?
+        .into_iter()
+        .map(|l| (l.index, l.name))
+        .collect();
+
+    let mut rtm: rtmsg = unsafe { mem::zeroed() };
+    rtm.rtm_family = libc::AF_UNSPEC as u8;
+    Ok(nl
+        .dump(NlMsgBuilder::new(RTM_GETROUTE, 0, &rtm))?
+        .iter()
+        .filter_map(|msg| parse_route(msg, &names))
+        .collect())
+}
+
+fn route_request(
+    nl: &mut NetlinkSocket,
+    ty: u16,
+    flags: u16,
+    route: &Route,
+) -> Result<NlMsgBuilder, IfError> {
+    let mut rtm: rtmsg = unsafe { mem::zeroed() };
+    rtm.rtm_family = match route.destination {
+        IpNetwork::V4(_) => libc::AF_INET as u8,
+        IpNetwork::V6(_) => libc::AF_INET6 as u8,
+    };
+    rtm.rtm_dst_len = route.destination.prefix();
+    rtm.rtm_table = if route.table < 256 {
+        route.table as u8
+    } else {
+        RT_TABLE_UNSPEC as u8
+    };
+    rtm.rtm_type = route.kind.into();
+    rtm.rtm_scope = match route.scope {
+        Some(scope) => scope.into(),
+        // the same defaults, as `ip route` uses
+        None if ty == RTM_DELROUTE => RT_SCOPE_NOWHERE,
+        None => match route.kind {
+            RouteType::Local | RouteType::Nat => RT_SCOPE_HOST,
+            RouteType::Broadcast | RouteType::Multicast | RouteType::Anycast => RT_SCOPE_LINK,
+            RouteType::Unicast if route.gateway.is_none() && route.nexthops.is_empty() => {
+                RT_SCOPE_LINK
+            }
+            _ => RT_SCOPE_UNIVERSE,
+        },
+    };
+    if ty != RTM_DELROUTE {
+        rtm.rtm_protocol = route.protocol.into();
+    }
+
+    let oif = match route.iface {
+        Some(ref ifname) => Some(get_link_by_name(nl, ifname)?.index),
+        None => None,
+    };
+    let multipath = if route.nexthops.is_empty() {
+        None
+    } else {
+        Some(nexthops_attr(nl, &route.nexthops)?)
+    };
+
+    let mut req = NlMsgBuilder::new(ty, flags, &rtm);
+    req.attr_u32(RTA_TABLE, route.table);
+    if route.destination.prefix() > 0 {
+        req.attr_ip(RTA_DST, route.destination.ip());
+    }
+    if let Some(gateway) = route.gateway {
+        req.attr_ip(RTA_GATEWAY, gateway);
+    }
+    if let Some(source) = route.source {
+        req.attr_ip(RTA_PREFSRC, source);
+    }
+    if let Some(oif) = oif {
+        req.attr_u32(RTA_OIF, oif as u32);
+    }
+    if let Some(metric) = route.metric {
+        req.attr_u32(RTA_PRIORITY, metric);
+    }
+    if let Some(ref multipath) = multipath {
+        req.attr(RTA_MULTIPATH, multipath);
+    }
+    Ok(req)
+}
+
+pub fn add_route(nl: &mut NetlinkSocket, route: &Route) -> Result<(), IfError> {
+    let req = route_request(nl, RTM_NEWROUTE, NLM_F_CREATE | NLM_F_EXCL, route)?;
+    nl.request(req)
+}
+
+pub fn replace_route(nl: &mut NetlinkSocket, route: &Route) -> Result<(), IfError> {
+    let req = route_request(nl, RTM_NEWROUTE, NLM_F_CREATE | NLM_F_REPLACE, route)?;
+    nl.request(req)
+}
+
+pub fn del_route(nl: &mut NetlinkSocket, route: &Route) -> Result<(), IfError> {
+    let req = route_request(nl, RTM_DELROUTE, 0, route)?;
+    nl.request(req)
+}
@@ -0,0 +1,114 @@
+use super::netlink::{attr_ip, attr_string, attr_u32, NetlinkSocket, NlMessage, NlMsgBuilder};
+use ifstructs::{
+    fib_rule_hdr, FIB_RULE_INVERT, FRA_DST, FRA_FWMARK, FRA_FWMASK, FRA_IIFNAME, FRA_OIFNAME,
+    FRA_PRIORITY, FRA_SRC, FRA_SUPPRESS_PREFIXLEN, FRA_TABLE, NLM_F_CREATE, NLM_F_EXCL,
+    RTM_DELRULE, RTM_GETRULE, RTM_NEWRULE, RT_TABLE_UNSPEC,
+};
+use ipnetwork::IpNetwork;
+use libc;
+use std::mem;
+use {IfError, IpFamily, Rule};
+
+fn parse_rule(msg: &NlMessage) -> Option<Rule> {
+    if msg.ty != RTM_NEWRULE {
+        return None;
+    }
+    let hdr: fib_rule_hdr = msg.header()?;
+    let family = match i32::from(hdr.family) {
+        libc::AF_INET => IpFamily::V4,
+        libc::AF_INET6 => IpFamily::V6,
+        _ => return None,
+    };
+
+    let mut rule = Rule::new(family);
+    rule.table = u32::from(hdr.table);
+    rule.action = hdr.action.into();
+    rule.invert = hdr.flags & FIB_RULE_INVERT != 0;
+
+    for (ty, data) in msg.attrs::<fib_rule_hdr>() {
+        match ty {
+            FRA_SRC => rule.source = IpNetwork::new(attr_ip(data)?, hdr.src_len).ok(),
+            FRA_DST => rule.destination = IpNetwork::new(attr_ip(data)?, hdr.dst_len).ok(),
+            FRA_IIFNAME => rule.iif = attr_string(data),
+            FRA_OIFNAME => rule.oif = attr_string(data),
+            FRA_PRIORITY => rule.priority = attr_u32(data),
+            FRA_FWMARK => rule.fwmark = attr_u32(data),
+            // kernel reports the full mask, if only the mark is set
+            FRA_FWMASK => rule.fwmask = attr_u32(data).filter(|v| *v != u32::MAX),
+            FRA_TABLE => rule.table = attr_u32(data)?,
+            // kernel reports -1, if it's not set
+            FRA_SUPPRESS_PREFIXLEN => {
+                rule.suppress_prefixlen = attr_u32(data).filter(|v| *v != u32::MAX)
+            }
+            _ => {}
+        }
+    }
+    Some(rule)
+}
+
+pub fn list_rules(nl: &mut NetlinkSocket) -> Result<Vec<Rule>, IfError> {
+    let mut hdr: fib_rule_hdr = unsafe { mem::zeroed() };
+    hdr.family = libc::AF_UNSPEC as u8;
+    Ok(nl
+        .dump(NlMsgBuilder::new(RTM_GETRULE, 0, &hdr))?
+        .iter()
+        .filter_map(parse_rule)
+        .collect())
+}
+
+fn rule_request(ty: u16, flags: u16, rule: &Rule) -> NlMsgBuilder {
+    let mut hdr: fib_rule_hdr = unsafe { mem::zeroed() };
+    hdr.family = match rule.family {
+        IpFamily::V4 => libc::AF_INET as u8,
+        IpFamily::V6 => libc::AF_INET6 as u8,
+    };
+    hdr.src_len = rule.source.map(|n| n.prefix()).unwrap_or(0);
+    hdr.dst_len = rule.destination.map(|n| n.prefix()).unwrap_or(0);
+    hdr.table = if rule.table < 256 {
+        rule.table as u8
+    } else {
+        RT_TABLE_UNSPEC as u8
+    };
+    hdr.action = rule.action.into();
+    if rule.invert {
+        hdr.flags |= FIB_RULE_INVERT;
+    }
+
+    let mut req = NlMsgBuilder::new(ty, flags, &hdr);
+    if rule.table != RT_TABLE_UNSPEC {
+        req.attr_u32(FRA_TABLE, rule.table);
+    }
+    if let Some(source) = rule.source {
+        req.attr_ip(FRA_SRC, source.ip());
+    }
+    if let Some(destination) = rule.destination {
+        req.attr_ip(FRA_DST, destination.ip());
+    }
+    if let Some(ref iif) = rule.iif {
+        req.attr_str(FRA_IIFNAME, iif);
+    }
+    if let Some(ref oif) = rule.oif {
+        req.attr_str(FRA_OIFNAME, oif);
+    }
+    if let Some(priority) = rule.priority {
+        req.attr_u32(FRA_PRIORITY, priority);
+    }
+    if let Some(fwmark) = rule.fwmark {
+        req.attr_u32(FRA_FWMARK, fwmark);
+    }
+    if let Some(fwmask) = rule.fwmask.filter(|v| *v != u32::MAX) {
+        req.attr_u32(FRA_FWMASK, fwmask);
+    }
+    if let Some(suppress_prefixlen) = rule.suppress_prefixlen {
+        req.attr_u32(FRA_SUPPRESS_PREFIXLEN, suppress_prefixlen);
+    }
+    req
+}
+
+pub fn add_rule(nl: &mut NetlinkSocket, rule: &Rule) -> Result<(), IfError> {
+    nl.request(rule_request(RTM_NEWRULE, NLM_F_CREATE | NLM_F_EXCL, rule))
+}
+
+pub fn del_rule(nl: &mut NetlinkSocket, rule: &Rule) -> Result<(), IfError> {
+    nl.request(rule_request(RTM_DELRULE, 0, rule))
+}
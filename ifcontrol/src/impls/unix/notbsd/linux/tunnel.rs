@@ -0,0 +1,269 @@
+use super::link::{
+    change_link_request, get_link_by_name, link_info_attr, new_link_request, LinkRecord,
+};
+use super::netlink::{attr_ip, attr_u16, attr_u32, attr_u8, NetlinkSocket, NlAttrs, NlMsgBuilder};
+use ifstructs::{
+    GRE_KEY, IFLA_GRE_IFLAGS, IFLA_GRE_IKEY, IFLA_GRE_LOCAL, IFLA_GRE_OFLAGS, IFLA_GRE_OKEY,
+    IFLA_GRE_PMTUDISC, IFLA_GRE_REMOTE, IFLA_GRE_TOS, IFLA_GRE_TTL, IFLA_IPTUN_LOCAL,
+    IFLA_IPTUN_PMTUDISC, IFLA_IPTUN_REMOTE, IFLA_IPTUN_TOS, IFLA_IPTUN_TTL,
+};
+use std::io;
+use {IfError, Tunnel, TunnelKind};
+
+fn kind_name(kind: TunnelKind) -> &'static str {
+    match kind {
+        TunnelKind::Gre => "gre",
+        TunnelKind::GreTap => "gretap",
+        TunnelKind::Ip6Gre => "ip6gre",
+        TunnelKind::Ipip => "ipip",
+        TunnelKind::Sit => "sit",
+        TunnelKind::Ip6Tnl => "ip6tnl",
+    }
+}
+
+fn is_gre(kind: TunnelKind) -> bool {
+    match kind {
+        TunnelKind::Gre | TunnelKind::GreTap | TunnelKind::Ip6Gre => true,
+        TunnelKind::Ipip | TunnelKind::Sit | TunnelKind::Ip6Tnl => false,
+    }
+}
+
+/// Kinds over IPv6, which have no TOS and path MTU discovery settings
+fn is_ipv6(kind: TunnelKind) -> bool {
+    match kind {
+        TunnelKind::Ip6Gre | TunnelKind::Ip6Tnl => true,
+        TunnelKind::Gre | TunnelKind::GreTap | TunnelKind::Ipip | TunnelKind::Sit => false,
+    }
+}
+
+/// The kernel silently ignores `tos` and `pmtudisc` of the IPv6 kinds
+fn check_tunnel(tunnel: &Tunnel) -> Result<(), IfError> {
+    if is_ipv6(tunnel.kind) && (tunnel.tos != 0 || !tunnel.pmtudisc) {
+        return Err(IfError::from(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "tos and pmtudisc are not supported by the IPv6 tunnels",
+        )));
+    }
+    Ok(())
+}
+
+/// GRE flags and key in the network byte order
+fn gre_key_attrs(req: &mut NlMsgBuilder, flags_ty: u16, key_ty: u16, key: Option<u32>) {
+    let flags = if key.is_some() { GRE_KEY } else { 0 };
+    req.attr_u16(flags_ty, flags.to_be());
+    req.attr_u32(key_ty, key.unwrap_or(0).to_be());
+}
+
+fn tunnel_info_attr(req: &mut NlMsgBuilder, tunnel: &Tunnel) {
+    link_info_attr(req, kind_name(tunnel.kind), |req| {
+        if is_gre(tunnel.kind) {
+            gre_key_attrs(req, IFLA_GRE_IFLAGS, IFLA_GRE_IKEY, tunnel.ikey);
+            gre_key_attrs(req, IFLA_GRE_OFLAGS, IFLA_GRE_OKEY, tunnel.okey);
+        }
+        let (local, remote, ttl, tos, pmtudisc) = if is_gre(tunnel.kind) {
+            (
+                IFLA_GRE_LOCAL,
+                IFLA_GRE_REMOTE,
+                IFLA_GRE_TTL,
+                IFLA_GRE_TOS,
+                IFLA_GRE_PMTUDISC,
+            )
+        } else {
+            (
+                IFLA_IPTUN_LOCAL,
+                IFLA_IPTUN_REMOTE,
+                IFLA_IPTUN_TTL,
+                IFLA_IPTUN_TOS,
+                IFLA_IPTUN_PMTUDISC,
+            )
+        };
+        if let Some(ip) = tunnel.local {
+            req.attr_ip(local, ip);
+        }
+        if let Some(ip) = tunnel.remote {
+            req.attr_ip(remote, ip);
+        }
+        req.attr_u8(ttl, tunnel.ttl);
+        if !is_ipv6(tunnel.kind) {
+            req.attr_u8(tos, tunnel.tos);
+            req.attr_u8(pmtudisc, tunnel.pmtudisc as u8);
+        }
+    });
+}
+
+fn tunnel_request(tunnel: &Tunnel) -> NlMsgBuilder {
+    let mut req = new_link_request(&tunnel.name);
+    tunnel_info_attr(&mut req, tunnel);
+    req
+}
+
+pub fn create_tunnel(nl: &mut NetlinkSocket, tunnel: &Tunnel) -> Result<(), IfError> {
+    check_tunnel(tunnel)?;
+    nl.request(tunnel_request(tunnel))
+}
+
+pub fn change_tunnel(nl: &mut NetlinkSocket, tunnel: &Tunnel) -> Result<(), IfError> {
+    check_tunnel(tunnel)?;
+    let link = get_link_by_name(nl, &tunnel.name)?;
+    let mut req = change_link_request(link.index);
+    tunnel_info_attr(&mut req, tunnel);
+    nl.request(req)
+}
+
+fn parse_gre_key(flags: Option<u16>, key: Option<u32>) -> Option<u32> {
+    if u16::from_be(flags?) & GRE_KEY != 0 {
+        key.map(u32::from_be)
+    } else {
+        None
+    }
+}
+
+/// Parses settings of the `link` with one of the tunnel kinds
+pub fn parse_tunnel(link: &LinkRecord) -> Option<Tunnel> {
+    let kind = match link.kind.as_deref()? {
+        "gre" => TunnelKind::Gre,
+        "gretap" => TunnelKind::GreTap,
+        "ip6gre" => TunnelKind::Ip6Gre,
+        "ipip" => TunnelKind::Ipip,
+        "sit" => TunnelKind::Sit,
+        "ip6tnl" => TunnelKind::Ip6Tnl,
+        _ => return None,
+    };
+    let mut tunnel = Tunnel::new(&link.name, kind);
+    let (mut iflags, mut ikey, mut oflags, mut okey) = (None, None, None, None);
+    for (ty, data) in NlAttrs::new(&link.info_data) {
+        if is_gre(kind) {
+            match ty {
+                IFLA_GRE_IFLAGS => iflags = attr_u16(data),
+                IFLA_GRE_IKEY => ikey = attr_u32(data),
+                IFLA_GRE_OFLAGS => oflags = attr_u16(data),
+                IFLA_GRE_OKEY => okey = attr_u32(data),
+                IFLA_GRE_LOCAL => tunnel.local = attr_ip(data),
+                IFLA_GRE_REMOTE => tunnel.remote = attr_ip(data),
+                IFLA_GRE_TTL => tunnel.ttl = attr_u8(data)?,
+                IFLA_GRE_TOS => tunnel.tos = attr_u8(data)?,
+                IFLA_GRE_PMTUDISC => tunnel.pmtudisc = attr_u8(data)? != 0,
+                _ => {}
+            }
+        } else {
+            match ty {
+                IFLA_IPTUN_LOCAL => tunnel.local = attr_ip(data),
+                IFLA_IPTUN_REMOTE => tunnel.remote = attr_ip(data),
+                IFLA_IPTUN_TTL => tunnel.ttl = attr_u8(data)?,
+                IFLA_IPTUN_TOS => tunnel.tos = attr_u8(data)?,
+                IFLA_IPTUN_PMTUDISC => tunnel.pmtudisc = attr_u8(data)? != 0,
+                _ => {}
+            }
+        }
+    }
+    tunnel.ikey = parse_gre_key(iflags, ikey);
+    tunnel.okey = parse_gre_key(oflags, okey);
+    // unspecified endpoints are reported as zero addresses
+    tunnel.local = tunnel.local.filter(|ip| !ip.is_unspecified());
+    tunnel.remote = tunnel.remote.filter(|ip| !ip.is_unspecified());
+    Some(tunnel)
+}
+
+pub fn get_tunnel(nl: &mut NetlinkSocket, ifname: &str) -> Result<Tunnel, IfError> {
+    parse_tunnel(&get_link_by_name(nl, ifname)?).ok_or(IfError::NotFound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::link::{link_message, link_request_attrs, parse_link};
+    use super::*;
+
+    #[test]
+    fn test_gre_request() {
+        let mut gre = Tunnel::new("gre0", TunnelKind::Gre);
+        gre.local = Some("10.0.0.1".parse().unwrap());
+        gre.remote = Some("10.0.0.2".parse().unwrap());
+        gre.ikey = Some(16);
+        gre.okey = Some(0x0102_0304);
+        gre.ttl = 64;
+        gre.tos = 0x10;
+        gre.pmtudisc = false;
+
+        let expected: Vec<u8> = [
+            // IFLA_IFNAME
+            &[9, 0, 3, 0][..],
+            b"gre0\0\0\0\0",
+            // IFLA_LINKINFO, IFLA_INFO_KIND
+            &[88, 0, 18, 0x80, 8, 0, 1, 0],
+            b"gre\0",
+            // IFLA_INFO_DATA, IFLA_GRE_IFLAGS: big-endian GRE_KEY
+            &[76, 0, 2, 0x80, 6, 0, 2, 0, 0x20, 0, 0, 0],
+            // IFLA_GRE_IKEY, big-endian
+            &[8, 0, 4, 0, 0, 0, 0, 16],
+            // IFLA_GRE_OFLAGS
+            &[6, 0, 3, 0, 0x20, 0, 0, 0],
+            // IFLA_GRE_OKEY
+            &[8, 0, 5, 0, 1, 2, 3, 4],
+            // IFLA_GRE_LOCAL
+            &[8, 0, 6, 0, 10, 0, 0, 1],
+            // IFLA_GRE_REMOTE
+            &[8, 0, 7, 0, 10, 0, 0, 2],
+            // IFLA_GRE_TTL
+            &[5, 0, 8, 0, 64, 0, 0, 0],
+            // IFLA_GRE_TOS
+            &[5, 0, 9, 0, 0x10, 0, 0, 0],
+            // IFLA_GRE_PMTUDISC
+            &[5, 0, 10, 0, 0, 0, 0, 0],
+        ]
+        .concat();
+        assert_eq!(link_request_attrs(tunnel_request(&gre)), expected);
+
+        let link = parse_link(&link_message(10, &expected)).unwrap();
+        assert_eq!(parse_tunnel(&link), Some(gre));
+    }
+
+    #[test]
+    fn test_ip6tnl_request() {
+        let mut ip6tnl = Tunnel::new("ip6tnl0", TunnelKind::Ip6Tnl);
+        ip6tnl.local = Some("fd00::1".parse().unwrap());
+        ip6tnl.remote = Some("fd00::2".parse().unwrap());
+        ip6tnl.ttl = 10;
+
+        let expected: Vec<u8> = [
+            // IFLA_IFNAME
+            &[12, 0, 3, 0][..],
+            b"ip6tnl0\0",
+            // IFLA_LINKINFO, IFLA_INFO_KIND
+            &[68, 0, 18, 0x80, 11, 0, 1, 0],
+            b"ip6tnl\0\0",
+            // IFLA_INFO_DATA, IFLA_IPTUN_LOCAL
+            &[52, 0, 2, 0x80, 20, 0, 2, 0],
+            &[0xfd, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+            // IFLA_IPTUN_REMOTE
+            &[20, 0, 3, 0],
+            &[0xfd, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2],
+            // IFLA_IPTUN_TTL
+            &[5, 0, 4, 0, 10, 0, 0, 0],
+        ]
+        .concat();
+        assert_eq!(link_request_attrs(tunnel_request(&ip6tnl)), expected);
+
+        let link = parse_link(&link_message(10, &expected)).unwrap();
+        assert_eq!(parse_tunnel(&link), Some(ip6tnl));
+    }
+
+    #[test]
+    fn test_ipv6_tos_pmtudisc() {
+        for &kind in &[TunnelKind::Ip6Gre, TunnelKind::Ip6Tnl] {
+            let mut tunnel = Tunnel::new("ip6tnl0", kind);
+            assert!(check_tunnel(&tunnel).is_ok());
+            tunnel.tos = 0x10;
+            match check_tunnel(&tunnel) {
+                Err(IfError::Io(ref e)) if e.kind() == io::ErrorKind::InvalidInput => {}
+                other => panic!("unexpected {:?}", other),
+            }
+            tunnel.tos = 0;
+            tunnel.pmtudisc = false;
+            assert!(check_tunnel(&tunnel).is_err());
+        }
+        let mut gre = Tunnel::new("gre0", TunnelKind::Gre);
+        gre.tos = 0x10;
+        gre.pmtudisc = false;
+        assert!(check_tunnel(&gre).is_ok());
+    }
+}
@@ -0,0 +1,45 @@
+use super::link::{link_info_attr, new_link_request};
+use super::netlink::{NetlinkSocket, NlMsgBuilder};
+use eui48::MacAddress;
+use ifstructs::{
+    ifinfomsg, IFLA_ADDRESS, IFLA_IFNAME, IFLA_MTU, IFLA_NET_NS_FD, IFLA_NUM_RX_QUEUES,
+    IFLA_NUM_TX_QUEUES, VETH_INFO_PEER,
+};
+use libc;
+use std::mem;
+use std::os::unix::io::AsRawFd;
+use {IfError, Veth};
+
+fn link_attrs(req: &mut NlMsgBuilder, hw_addr: Option<MacAddress>, veth: &Veth) {
+    if let Some(hw_addr) = hw_addr {
+        req.attr(IFLA_ADDRESS, hw_addr.as_bytes());
+    }
+    if let Some(mtu) = veth.mtu {
+        req.attr_u32(IFLA_MTU, mtu);
+    }
+    if let Some(queues) = veth.queues {
+        req.attr_u32(IFLA_NUM_TX_QUEUES, queues);
+        req.attr_u32(IFLA_NUM_RX_QUEUES, queues);
+    }
+}
+
+pub fn create_veth(nl: &mut NetlinkSocket, veth: &Veth) -> Result<(), IfError> {
+    let mut req = new_link_request(&veth.name);
+    link_attrs(&mut req, veth.hw_addr, veth);
+
+    link_info_attr(&mut req, "veth", |req| {
+        let peer = req.begin_nested(VETH_INFO_PEER);
+        // peer is described by the complete link message
+        let mut ifi: ifinfomsg = unsafe { mem::zeroed() };
+        ifi.ifi_family = libc::AF_UNSPEC as u8;
+        req.append(&ifi);
+        req.attr_str(IFLA_IFNAME, &veth.peer_name);
+        link_attrs(req, veth.peer_hw_addr, veth);
+        if let Some(ref netns) = veth.peer_netns {
+            req.attr_u32(IFLA_NET_NS_FD, netns.as_raw_fd() as u32);
+        }
+        req.end_nested(peer);
+    });
+
+    nl.request(req)
+}
@@ -0,0 +1,138 @@
+use super::link::{
+    get_link_by_name, get_link_with_parent, link_info_attr, new_link_request, LinkRecord,
+};
+use super::netlink::{as_bytes, attr_u16, read_struct, NetlinkSocket, NlAttrs, NlMsgBuilder};
+use ifstructs::{
+    ifla_vlan_flags, ifla_vlan_qos_mapping, VlanFlags, IFLA_LINK, IFLA_VLAN_EGRESS_QOS,
+    IFLA_VLAN_FLAGS, IFLA_VLAN_ID, IFLA_VLAN_INGRESS_QOS, IFLA_VLAN_PROTOCOL,
+    IFLA_VLAN_QOS_MAPPING,
+};
+use libc;
+use {IfError, Vlan, VlanProtocol};
+
+fn qos_map_attr(req: &mut NlMsgBuilder, ty: u16, map: &[(u32, u32)]) {
+    if map.is_empty() {
+        return;
+    }
+    let qos = req.begin_nested(ty);
+    for &(from, to) in map {
+        let mapping = ifla_vlan_qos_mapping { from, to };
+        req.attr(IFLA_VLAN_QOS_MAPPING, unsafe { as_bytes(&mapping) });
+    }
+    req.end_nested(qos);
+}
+
+fn vlan_request(parent: libc::c_int, vlan: &Vlan) -> NlMsgBuilder {
+    let mut req = new_link_request(&vlan.name);
+    req.attr_u32(IFLA_LINK, parent as u32);
+    link_info_attr(&mut req, "vlan", |req| {
+        req.attr_u16(IFLA_VLAN_ID, vlan.id);
+        let protocol = match vlan.protocol {
+            VlanProtocol::Dot1Q => libc::ETH_P_8021Q,
+            VlanProtocol::Dot1AD => libc::ETH_P_8021AD,
+        };
+        // the only big-endian attribute
+        req.attr_u16(IFLA_VLAN_PROTOCOL, (protocol as u16).to_be());
+        let flags = ifla_vlan_flags {
+            flags: vlan.flags.bits(),
+            mask: VlanFlags::all().bits(),
+        };
+        req.attr(IFLA_VLAN_FLAGS, unsafe { as_bytes(&flags) });
+        qos_map_attr(req, IFLA_VLAN_INGRESS_QOS, &vlan.ingress_qos_map);
+        qos_map_attr(req, IFLA_VLAN_EGRESS_QOS, &vlan.egress_qos_map);
+    });
+    req
+}
+
+pub fn create_vlan(nl: &mut NetlinkSocket, vlan: &Vlan) -> Result<(), IfError> {
+    let parent = get_link_by_name(nl, &vlan.parent)?;
+    nl.request(vlan_request(parent.index, vlan))
+}
+
+fn parse_qos_map(data: &[u8]) -> Vec<(u32, u32)> {
+    NlAttrs::new(data)
+        .filter(|&(ty, _)| ty == IFLA_VLAN_QOS_MAPPING)
+        .filter_map(|(_, data)| read_struct::<ifla_vlan_qos_mapping>(data))
+        .map(|m| (m.from, m.to))
+        .collect()
+}
+
+/// Parses settings of the `link` with "vlan" kind. `parent` is the name of the parent interface
+pub fn parse_vlan(link: &LinkRecord, parent: &str) -> Option<Vlan> {
+    if link.kind.as_deref() != Some("vlan") {
+        return None;
+    }
+    let mut vlan = Vlan::new(&link.name, parent, 0);
+    vlan.flags = VlanFlags::empty();
+    let mut id = None;
+    for (ty, data) in NlAttrs::new(&link.info_data) {
+        match ty {
+            IFLA_VLAN_ID => id = attr_u16(data),
+            IFLA_VLAN_PROTOCOL => {
+                vlan.protocol = match i32::from(u16::from_be(attr_u16(data)?)) {
+                    libc::ETH_P_8021AD => VlanProtocol::Dot1AD,
+                    _ => VlanProtocol::Dot1Q,
+                }
+            }
+            IFLA_VLAN_FLAGS => {
+                let flags: ifla_vlan_flags = read_struct(data)?;
+                vlan.flags = VlanFlags::from_bits_truncate(flags.flags);
+            }
+            IFLA_VLAN_INGRESS_QOS => vlan.ingress_qos_map = parse_qos_map(data),
+            IFLA_VLAN_EGRESS_QOS => vlan.egress_qos_map = parse_qos_map(data),
+            _ => {}
+        }
+    }
+    vlan.id = id?;
+    Some(vlan)
+}
+
+pub fn get_vlan(nl: &mut NetlinkSocket, ifname: &str) -> Result<Vlan, IfError> {
+    let (link, parent) = get_link_with_parent(nl, ifname)?;
+    parse_vlan(&link, &parent).ok_or(IfError::NotFound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::link::{link_message, link_request_attrs, parse_link};
+    use super::*;
+
+    #[test]
+    fn test_vlan_request() {
+        let mut vlan = Vlan::new("eth0.100", "eth0", 100);
+        vlan.protocol = VlanProtocol::Dot1AD;
+        vlan.flags |= VlanFlags::VLAN_FLAG_GVRP;
+        vlan.ingress_qos_map = vec![(1, 2), (3, 4)];
+        vlan.egress_qos_map = vec![(5, 6)];
+
+        let expected: Vec<u8> = [
+            // IFLA_IFNAME
+            &[13, 0, 3, 0][..],
+            b"eth0.100\0\0\0\0",
+            // IFLA_LINK
+            &[8, 0, 5, 0, 2, 0, 0, 0],
+            // IFLA_LINKINFO, IFLA_INFO_KIND
+            &[92, 0, 18, 0x80, 9, 0, 1, 0],
+            b"vlan\0\0\0\0",
+            // IFLA_INFO_DATA, IFLA_VLAN_ID
+            &[76, 0, 2, 0x80, 6, 0, 1, 0, 100, 0, 0, 0],
+            // IFLA_VLAN_PROTOCOL, big-endian ETH_P_8021AD
+            &[6, 0, 5, 0, 0x88, 0xa8, 0, 0],
+            // IFLA_VLAN_FLAGS: REORDER_HDR | GVRP, mask of all the flags
+            &[12, 0, 2, 0, 3, 0, 0, 0, 0x1f, 0, 0, 0],
+            // IFLA_VLAN_INGRESS_QOS with two IFLA_VLAN_QOS_MAPPING
+            &[28, 0, 4, 0x80],
+            &[12, 0, 1, 0, 1, 0, 0, 0, 2, 0, 0, 0],
+            &[12, 0, 1, 0, 3, 0, 0, 0, 4, 0, 0, 0],
+            // IFLA_VLAN_EGRESS_QOS
+            &[16, 0, 3, 0x80, 12, 0, 1, 0, 5, 0, 0, 0, 6, 0, 0, 0],
+        ]
+        .concat();
+        assert_eq!(link_request_attrs(vlan_request(2, &vlan)), expected);
+
+        let link = parse_link(&link_message(10, &expected)).expect("parse link");
+        assert_eq!(link.name, "eth0.100");
+        assert_eq!(link.parent, Some(2));
+        assert_eq!(parse_vlan(&link, "eth0"), Some(vlan));
+    }
+}
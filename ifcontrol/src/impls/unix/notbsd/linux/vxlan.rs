@@ -0,0 +1,220 @@
+use super::link::{
+    get_link_by_index, get_link_by_name, link_info_attr, new_link_request, LinkRecord,
+};
+use super::netlink::{attr_ip, attr_u16, attr_u32, attr_u8, NetlinkSocket, NlAttrs, NlMsgBuilder};
+use ifstructs::{
+    IFLA_GENEVE_ID, IFLA_GENEVE_PORT, IFLA_GENEVE_REMOTE, IFLA_GENEVE_REMOTE6, IFLA_GENEVE_TTL,
+    IFLA_GENEVE_UDP_CSUM, IFLA_VXLAN_GROUP, IFLA_VXLAN_GROUP6, IFLA_VXLAN_ID, IFLA_VXLAN_LEARNING,
+    IFLA_VXLAN_LINK, IFLA_VXLAN_LOCAL, IFLA_VXLAN_LOCAL6, IFLA_VXLAN_PORT, IFLA_VXLAN_TTL,
+    IFLA_VXLAN_UDP_CSUM,
+};
+use libc;
+use std::io;
+use std::net::IpAddr;
+use {Geneve, IfError, Vxlan};
+
+/// Picks IPv4 or IPv6 variant of the attribute
+fn attr_ip_of(req: &mut NlMsgBuilder, ty_v4: u16, ty_v6: u16, ip: IpAddr) {
+    let ty = if ip.is_ipv4() { ty_v4 } else { ty_v6 };
+    req.attr_ip(ty, ip);
+}
+
+fn vxlan_request(vxlan: &Vxlan, dev: Option<libc::c_int>) -> NlMsgBuilder {
+    let mut req = new_link_request(&vxlan.name);
+    link_info_attr(&mut req, "vxlan", |req| {
+        req.attr_u32(IFLA_VXLAN_ID, vxlan.vni);
+        if let Some(local) = vxlan.local {
+            attr_ip_of(req, IFLA_VXLAN_LOCAL, IFLA_VXLAN_LOCAL6, local);
+        }
+        // both are the default destination, which is a group if it is multicast
+        if let Some(remote) = vxlan.group.or(vxlan.remote) {
+            attr_ip_of(req, IFLA_VXLAN_GROUP, IFLA_VXLAN_GROUP6, remote);
+        }
+        if let Some(dev) = dev {
+            req.attr_u32(IFLA_VXLAN_LINK, dev as u32);
+        }
+        req.attr_u16(IFLA_VXLAN_PORT, vxlan.port.to_be());
+        req.attr_u8(IFLA_VXLAN_TTL, vxlan.ttl);
+        req.attr_u8(IFLA_VXLAN_LEARNING, vxlan.learning as u8);
+        req.attr_u8(IFLA_VXLAN_UDP_CSUM, vxlan.udp_csum as u8);
+    });
+    req
+}
+
+pub fn create_vxlan(nl: &mut NetlinkSocket, vxlan: &Vxlan) -> Result<(), IfError> {
+    // the kernel has a single default destination for both
+    if vxlan.group.is_some() && vxlan.remote.is_some() {
+        return Err(IfError::from(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "vxlan can't have both group and remote",
+        )));
+    }
+    let dev = match vxlan.dev {
+        Some(ref dev) => Some(get_link_by_name(nl, dev)?.index),
+        None => None,
+    };
+    nl.request(vxlan_request(vxlan, dev))
+}
+
+/// Parses settings of the `link` with "vxlan" kind. Returns them along with the underlay
+/// interface index, as `dev` is left unset
+fn parse_vxlan(link: &LinkRecord) -> Option<(Vxlan, Option<libc::c_int>)> {
+    if link.kind.as_deref() != Some("vxlan") {
+        return None;
+    }
+    let mut vxlan = Vxlan::new(&link.name, 0);
+    let mut vni = None;
+    let mut dev = None;
+    for (ty, data) in NlAttrs::new(&link.info_data) {
+        match ty {
+            IFLA_VXLAN_ID => vni = attr_u32(data),
+            IFLA_VXLAN_LOCAL | IFLA_VXLAN_LOCAL6 => vxlan.local = attr_ip(data),
+            IFLA_VXLAN_GROUP | IFLA_VXLAN_GROUP6 => {
+                let ip = attr_ip(data)?;
+                if ip.is_multicast() {
+                    vxlan.group = Some(ip);
+                } else {
+                    vxlan.remote = Some(ip);
+                }
+            }
+            IFLA_VXLAN_LINK => dev = attr_u32(data).map(|i| i as libc::c_int),
+            IFLA_VXLAN_PORT => vxlan.port = u16::from_be(attr_u16(data)?),
+            IFLA_VXLAN_TTL => vxlan.ttl = attr_u8(data)?,
+            IFLA_VXLAN_LEARNING => vxlan.learning = attr_u8(data)? != 0,
+            IFLA_VXLAN_UDP_CSUM => vxlan.udp_csum = attr_u8(data)? != 0,
+            _ => {}
+        }
+    }
+    vxlan.vni = vni?;
+    Some((vxlan, dev))
+}
+
+pub fn get_vxlan(nl: &mut NetlinkSocket, ifname: &str) -> Result<Vxlan, IfError> {
+    let link = get_link_by_name(nl, ifname)?;
+    let (mut vxlan, dev) = parse_vxlan(&link).ok_or(IfError::NotFound)?;
+    if let Some(dev) = dev {
+        vxlan.dev = Some(get_link_by_index(nl, dev)?.name);
+    }
+    Ok(vxlan)
+}
+
+fn geneve_request(geneve: &Geneve) -> NlMsgBuilder {
+    let mut req = new_link_request(&geneve.name);
+    link_info_attr(&mut req, "geneve", |req| {
+        req.attr_u32(IFLA_GENEVE_ID, geneve.vni);
+        if let Some(remote) = geneve.remote {
+            attr_ip_of(req, IFLA_GENEVE_REMOTE, IFLA_GENEVE_REMOTE6, remote);
+        }
+        req.attr_u16(IFLA_GENEVE_PORT, geneve.port.to_be());
+        req.attr_u8(IFLA_GENEVE_TTL, geneve.ttl);
+        req.attr_u8(IFLA_GENEVE_UDP_CSUM, geneve.udp_csum as u8);
+    });
+    req
+}
+
+pub fn create_geneve(nl: &mut NetlinkSocket, geneve: &Geneve) -> Result<(), IfError> {
+    nl.request(geneve_request(geneve))
+}
+
+/// Parses settings of the `link` with "geneve" kind
+pub fn parse_geneve(link: &LinkRecord) -> Option<Geneve> {
+    if link.kind.as_deref() != Some("geneve") {
+        return None;
+    }
+    let mut geneve = Geneve::new(&link.name, 0);
+    let mut vni = None;
+    for (ty, data) in NlAttrs::new(&link.info_data) {
+        match ty {
+            IFLA_GENEVE_ID => vni = attr_u32(data),
+            IFLA_GENEVE_REMOTE | IFLA_GENEVE_REMOTE6 => geneve.remote = attr_ip(data),
+            IFLA_GENEVE_PORT => geneve.port = u16::from_be(attr_u16(data)?),
+            IFLA_GENEVE_TTL => geneve.ttl = attr_u8(data)?,
+            IFLA_GENEVE_UDP_CSUM => geneve.udp_csum = attr_u8(data)? != 0,
+            _ => {}
+        }
+    }
+    geneve.vni = vni?;
+    Some(geneve)
+}
+
+pub fn get_geneve(nl: &mut NetlinkSocket, ifname: &str) -> Result<Geneve, IfError> {
+    parse_geneve(&get_link_by_name(nl, ifname)?).ok_or(IfError::NotFound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::link::{link_message, link_request_attrs, parse_link};
+    use super::*;
+
+    #[test]
+    fn test_vxlan_request() {
+        let mut vxlan = Vxlan::new("vxlan0", 42);
+        vxlan.local = Some("fd00::1".parse().unwrap());
+        vxlan.group = Some("ff05::100".parse().unwrap());
+        vxlan.ttl = 10;
+        vxlan.learning = false;
+
+        let expected: Vec<u8> = [
+            // IFLA_IFNAME
+            &[11, 0, 3, 0][..],
+            b"vxlan0\0\0",
+            // IFLA_LINKINFO, IFLA_INFO_KIND
+            &[108, 0, 18, 0x80, 10, 0, 1, 0],
+            b"vxlan\0\0\0",
+            // IFLA_INFO_DATA, IFLA_VXLAN_ID
+            &[92, 0, 2, 0x80, 8, 0, 1, 0, 42, 0, 0, 0],
+            // IFLA_VXLAN_LOCAL6
+            &[20, 0, 17, 0],
+            &[0xfd, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+            // IFLA_VXLAN_GROUP6
+            &[20, 0, 16, 0],
+            &[0xff, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0],
+            // IFLA_VXLAN_LINK
+            &[8, 0, 3, 0, 3, 0, 0, 0],
+            // IFLA_VXLAN_PORT, big-endian 4789
+            &[6, 0, 15, 0, 0x12, 0xb5, 0, 0],
+            // IFLA_VXLAN_TTL
+            &[5, 0, 5, 0, 10, 0, 0, 0],
+            // IFLA_VXLAN_LEARNING
+            &[5, 0, 7, 0, 0, 0, 0, 0],
+            // IFLA_VXLAN_UDP_CSUM
+            &[5, 0, 18, 0, 1, 0, 0, 0],
+        ]
+        .concat();
+        assert_eq!(link_request_attrs(vxlan_request(&vxlan, Some(3))), expected);
+
+        let link = parse_link(&link_message(10, &expected)).unwrap();
+        assert_eq!(parse_vxlan(&link), Some((vxlan, Some(3))));
+    }
+
+    #[test]
+    fn test_geneve_request() {
+        let mut geneve = Geneve::new("geneve0", 42);
+        geneve.remote = Some("10.0.0.2".parse().unwrap());
+        geneve.port = 6082;
+
+        let expected: Vec<u8> = [
+            // IFLA_IFNAME
+            &[12, 0, 3, 0][..],
+            b"geneve0\0",
+            // IFLA_LINKINFO, IFLA_INFO_KIND
+            &[60, 0, 18, 0x80, 11, 0, 1, 0],
+            b"geneve\0\0",
+            // IFLA_INFO_DATA, IFLA_GENEVE_ID
+            &[44, 0, 2, 0x80, 8, 0, 1, 0, 42, 0, 0, 0],
+            // IFLA_GENEVE_REMOTE
+            &[8, 0, 2, 0, 10, 0, 0, 2],
+            // IFLA_GENEVE_PORT, big-endian 6082
+            &[6, 0, 5, 0, 0x17, 0xc2, 0, 0],
+            // IFLA_GENEVE_TTL
+            &[5, 0, 3, 0, 0, 0, 0, 0],
+            // IFLA_GENEVE_UDP_CSUM
+            &[5, 0, 8, 0, 1, 0, 0, 0],
+        ]
+        .concat();
+        assert_eq!(link_request_attrs(geneve_request(&geneve)), expected);
+
+        let link = parse_link(&link_message(10, &expected)).unwrap();
+        assert_eq!(parse_geneve(&link), Some(geneve));
+    }
+}
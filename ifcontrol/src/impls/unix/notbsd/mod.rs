@@ -1,12 +1,47 @@
+use eui48::MacAddress;
 use ifstructs::ifreq;
 use libc;
+use nix;
+use std::os::unix::io::AsRawFd;
+use IfError;
 
 // #define SIOCGIFFLAGS	0x8913		/* get flags			*/
 ioctl_readwrite_bad!(iface_get_flags, libc::SIOCGIFFLAGS, ifreq);
 // #define SIOCSIFFLAGS	0x8914		/* set flags			*/
 ioctl_write_ptr_bad!(iface_set_flags, libc::SIOCSIFFLAGS, ifreq);
+// #define SIOCGIFMTU	0x8921		/* get MTU size			*/
+ioctl_readwrite_bad!(iface_get_mtu, libc::SIOCGIFMTU, ifreq);
+// #define SIOCSIFMTU	0x8922		/* set MTU size			*/
+ioctl_write_ptr_bad!(iface_set_mtu, libc::SIOCSIFMTU, ifreq);
 // // #define SIOCGIFHWADDR	0x8927
 // ioctl_write_ptr_bad!(iface_get_hwaddr, libc::SIOCGIFHWADDR, ifreq);
+// #define SIOCSIFNAME	0x8923		/* set interface name		*/
+ioctl_write_ptr_bad!(iface_set_name, libc::SIOCSIFNAME, ifreq);
+// #define SIOCSIFHWADDR	0x8924		/* set hardware address 	*/
+ioctl_write_ptr_bad!(iface_set_hwaddr, libc::SIOCSIFHWADDR, ifreq);
+
+pub fn rename_iface<F: AsRawFd>(ctl_fd: &F, ifname: &str, new_ifname: &str) -> Result<(), IfError> {
+    let mut req = ifreq::from_name(ifname)?;
+
+    req.set_new_name(new_ifname)?;
+
+    unsafe { iface_set_name(ctl_fd.as_raw_fd(), &req) }?;
+
+    Ok(())
+}
+
+pub fn set_hw_addr<F: AsRawFd>(ctl_fd: &F, ifname: &str, addr: MacAddress) -> Result<(), IfError> {
+    let mut req = ifreq::from_name(ifname)?;
+
+    unsafe { req.set_hw_addr(addr.to_array()) };
+
+    match unsafe { iface_set_hwaddr(ctl_fd.as_raw_fd(), &req) } {
+        Ok(_) => Ok(()),
+        // device doesn't support changing the address while it's up
+        Err(nix::Error::Sys(nix::errno::Errno::EBUSY)) => Err(IfError::MustBeDown),
+        Err(e) => Err(e.into()),
+    }
+}
 
 cfg_if! {
     if #[cfg(target_os = "android")] {
@@ -10,8 +10,74 @@ extern crate nix;
 #[macro_use]
 extern crate failure;
 extern crate ipnetwork;
+#[cfg(target_os = "linux")]
+#[macro_use]
+extern crate futures;
+#[cfg(target_os = "linux")]
+extern crate mio;
+#[cfg(all(test, target_os = "linux"))]
+extern crate tokio;
+#[cfg(target_os = "linux")]
+extern crate tokio_reactor;
 
+#[cfg(target_os = "linux")]
+mod bond;
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+mod bridge;
+#[cfg(target_os = "linux")]
+mod ethtool;
+#[cfg(target_os = "linux")]
+mod events;
+#[cfg(target_os = "linux")]
+mod fdb;
 mod impls;
+#[cfg(target_os = "linux")]
+mod macvlan;
+#[cfg(target_os = "linux")]
+mod neigh;
+#[cfg(target_os = "linux")]
+mod netns;
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+mod route;
+#[cfg(target_os = "linux")]
+mod rule;
+#[cfg(target_os = "linux")]
+mod tunnel;
+#[cfg(target_os = "linux")]
+mod veth;
+#[cfg(target_os = "linux")]
+mod vlan;
+#[cfg(target_os = "linux")]
+mod vxlan;
+
+#[cfg(target_os = "linux")]
+pub use bond::*;
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+pub use bridge::*;
+#[cfg(target_os = "linux")]
+pub use ethtool::*;
+#[cfg(target_os = "linux")]
+pub use events::*;
+#[cfg(target_os = "linux")]
+pub use fdb::*;
+#[cfg(target_os = "linux")]
+pub use macvlan::*;
+#[cfg(target_os = "linux")]
+pub use neigh::*;
+#[cfg(target_os = "linux")]
+pub use netns::*;
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+pub use route::*;
+#[cfg(target_os = "linux")]
+pub use rule::*;
+#[cfg(target_os = "linux")]
+pub use tunnel::*;
+#[cfg(target_os = "linux")]
+pub use veth::*;
+#[cfg(target_os = "linux")]
+pub use vlan::*;
+#[cfg(target_os = "linux")]
+pub use vxlan::*;
 
 #[derive(Debug, Fail)]
 #[fail(display = "interface control error")]
@@ -23,6 +89,8 @@ pub enum IfError {
     Io(#[cause] ::std::io::Error),
     #[fail(display = "iface not found")]
     NotFound,
+    #[fail(display = "iface must be down")]
+    MustBeDown,
 }
 
 #[cfg(unix)]
@@ -40,10 +108,12 @@ impl From<::std::io::Error> for IfError {
 
 use eui48::MacAddress;
 #[cfg(unix)]
-use ifstructs::IfFlags;
+pub use ifstructs::IfFlags;
+#[cfg(target_os = "linux")]
+use std::net::Ipv4Addr;
 use std::net::IpAddr;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Link {
     Regular,
     Tun,
@@ -51,6 +121,204 @@ pub enum Link {
     Bridge,
     Loopback,
     Ethernet,
+    Veth,
+    Vlan { parent: String, id: u16 },
+    Macvlan,
+    Vxlan,
+    Bond,
+    Dummy,
+    Wireguard,
+    /// Any of GRE kinds: gre, gretap, ip6gre and ip6gretap
+    Gre,
+    Vrf,
+    /// Virtual interface of the kind, which has no own variant, e.g. "ipvlan" or "geneve"
+    Other(String),
+}
+
+/// Scope of an address or a route (`RT_SCOPE_*`)
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    Universe,
+    Site,
+    Link,
+    Host,
+    Nowhere,
+    Other(u8),
+}
+
+#[cfg(target_os = "linux")]
+impl From<u8> for Scope {
+    fn from(v: u8) -> Scope {
+        match v {
+            ifstructs::RT_SCOPE_UNIVERSE => Scope::Universe,
+            ifstructs::RT_SCOPE_SITE => Scope::Site,
+            ifstructs::RT_SCOPE_LINK => Scope::Link,
+            ifstructs::RT_SCOPE_HOST => Scope::Host,
+            ifstructs::RT_SCOPE_NOWHERE => Scope::Nowhere,
+            v => Scope::Other(v),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl From<Scope> for u8 {
+    fn from(scope: Scope) -> u8 {
+        match scope {
+            Scope::Universe => ifstructs::RT_SCOPE_UNIVERSE,
+            Scope::Site => ifstructs::RT_SCOPE_SITE,
+            Scope::Link => ifstructs::RT_SCOPE_LINK,
+            Scope::Host => ifstructs::RT_SCOPE_HOST,
+            Scope::Nowhere => ifstructs::RT_SCOPE_NOWHERE,
+            Scope::Other(v) => v,
+        }
+    }
+}
+
+/// RFC 2863 operational state of the interface (`IF_OPER_*`)
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperState {
+    /// Driver doesn't report the state, e.g. loopback and tun
+    Unknown,
+    NotPresent,
+    Down,
+    /// Interface, this one is stacked on, is down
+    LowerLayerDown,
+    Testing,
+    /// Waiting for an external event, e.g. 802.1X authentication
+    Dormant,
+    Up,
+}
+
+#[cfg(target_os = "linux")]
+impl From<u8> for OperState {
+    fn from(v: u8) -> OperState {
+        match v {
+            ifstructs::IF_OPER_NOTPRESENT => OperState::NotPresent,
+            ifstructs::IF_OPER_DOWN => OperState::Down,
+            ifstructs::IF_OPER_LOWERLAYERDOWN => OperState::LowerLayerDown,
+            ifstructs::IF_OPER_TESTING => OperState::Testing,
+            ifstructs::IF_OPER_DORMANT => OperState::Dormant,
+            ifstructs::IF_OPER_UP => OperState::Up,
+            _ => OperState::Unknown,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub use ifstructs::IfaFlags;
+#[cfg(target_os = "linux")]
+pub use impls::DriverInfo;
+
+/// Address of the interface along with its network
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IfaceAddr {
+    pub network: ipnetwork::IpNetwork,
+    pub scope: Scope,
+    /// `IFA_F_TENTATIVE`, `IFA_F_DEPRECATED`, `IFA_F_PERMANENT` etc.
+    pub flags: IfaFlags,
+}
+
+/// Address family of a policy routing rule or a neighbor entry
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpFamily {
+    V4,
+    V6,
+}
+
+/// Optional parameters of the address, added with `Iface::add_addr_with_options`
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Default)]
+pub struct AddrOptions {
+    /// IPv4 broadcast address. The last address of the network is used by default
+    pub broadcast: Option<Ipv4Addr>,
+    /// `Scope::Universe` by default
+    pub scope: Option<Scope>,
+    /// Valid lifetime in seconds. The address never expires by default
+    pub valid_lifetime: Option<u32>,
+    /// Preferred lifetime in seconds. Equals to the valid lifetime by default
+    pub preferred_lifetime: Option<u32>,
+}
+
+/// Interface counters, including the detailed error breakdown
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IfaceStats {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    /// Received multicast packets
+    pub multicast: u64,
+    pub collisions: u64,
+
+    pub rx_length_errors: u64,
+    /// Receiver ring buffer overflows
+    pub rx_over_errors: u64,
+    pub rx_crc_errors: u64,
+    pub rx_frame_errors: u64,
+    pub rx_fifo_errors: u64,
+    pub rx_missed_errors: u64,
+
+    pub tx_aborted_errors: u64,
+    pub tx_carrier_errors: u64,
+    pub tx_fifo_errors: u64,
+    pub tx_heartbeat_errors: u64,
+    pub tx_window_errors: u64,
+
+    pub rx_compressed: u64,
+    pub tx_compressed: u64,
+    /// Dropped, because no protocol handler was found
+    pub rx_nohandler: u64,
+}
+
+#[cfg(target_os = "linux")]
+impl From<ifstructs::rtnl_link_stats64> for IfaceStats {
+    fn from(s: ifstructs::rtnl_link_stats64) -> IfaceStats {
+        IfaceStats {
+            rx_packets: s.rx_packets,
+            tx_packets: s.tx_packets,
+            rx_bytes: s.rx_bytes,
+            tx_bytes: s.tx_bytes,
+            rx_errors: s.rx_errors,
+            tx_errors: s.tx_errors,
+            rx_dropped: s.rx_dropped,
+            tx_dropped: s.tx_dropped,
+            multicast: s.multicast,
+            collisions: s.collisions,
+            rx_length_errors: s.rx_length_errors,
+            rx_over_errors: s.rx_over_errors,
+            rx_crc_errors: s.rx_crc_errors,
+            rx_frame_errors: s.rx_frame_errors,
+            rx_fifo_errors: s.rx_fifo_errors,
+            rx_missed_errors: s.rx_missed_errors,
+            tx_aborted_errors: s.tx_aborted_errors,
+            tx_carrier_errors: s.tx_carrier_errors,
+            tx_fifo_errors: s.tx_fifo_errors,
+            tx_heartbeat_errors: s.tx_heartbeat_errors,
+            tx_window_errors: s.tx_window_errors,
+            rx_compressed: s.rx_compressed,
+            tx_compressed: s.tx_compressed,
+            rx_nohandler: s.rx_nohandler,
+        }
+    }
+}
+
+/// Optional parameters of the interface, created with `Iface::create`
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Default)]
+pub struct LinkOptions {
+    /// Default MTU of the kind is used by default
+    pub mtu: Option<u32>,
+    /// Random address is generated by the kernel by default
+    pub hw_addr: Option<MacAddress>,
 }
 
 pub type AddressIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, IpAddr>>;
@@ -63,11 +331,29 @@ pub struct Iface {
     hw_addr: Option<MacAddress>,
     flags: IfFlags,
     link: Link,
+    #[cfg(target_os = "linux")]
+    index: u32,
+    #[cfg(target_os = "linux")]
+    mtu: u32,
+    #[cfg(target_os = "linux")]
+    addrs: Vec<IfaceAddr>,
+    #[cfg(target_os = "linux")]
+    operstate: OperState,
+    #[cfg(target_os = "linux")]
+    link_type: u16,
+    #[cfg(target_os = "linux")]
+    txqueuelen: u32,
+    #[cfg(target_os = "linux")]
+    qdisc: Option<String>,
+    #[cfg(target_os = "linux")]
+    master_index: Option<u32>,
+    #[cfg(target_os = "linux")]
+    parent_index: Option<u32>,
 }
 
 #[cfg(unix)]
 impl Iface {
-    #[cfg(all(unix, not(target_os = "android")))]
+    #[cfg(all(unix, not(any(target_os = "android", target_os = "linux"))))]
     pub fn all() -> Result<Vec<Iface>, IfError> {
         use std::collections::HashMap;
 
@@ -133,7 +419,7 @@ impl Iface {
         Ok(ifaces)
     }
 
-    #[cfg(all(unix, not(target_os = "android")))]
+    #[cfg(all(unix, not(any(target_os = "android", target_os = "linux"))))]
     pub fn find_by_name(ifname: &str) -> Result<Iface, IfError> {
         let iface = Self::all()?
             .into_iter()
@@ -149,10 +435,23 @@ impl Iface {
         Err(IfError::NotFound)
     }
 
+    pub fn name(&self) -> &str {
+        &self.ifname
+    }
+
+    pub fn link(&self) -> &Link {
+        &self.link
+    }
+
     pub fn hw_addr(&self) -> Option<MacAddress> {
         self.hw_addr
     }
 
+    /// `IFF_*` flags at the time of the lookup
+    pub fn flags(&self) -> IfFlags {
+        self.flags
+    }
+
     pub fn is_up(&self) -> Result<bool, IfError> {
         let ctl_fd = impls::new_control_socket()?;
         impls::is_up(&ctl_fd, &self.ifname)
@@ -176,16 +475,63 @@ impl Iface {
         impls::down(&ctl_fd, ifname)
     }
 
+    #[cfg(not(target_os = "linux"))]
+    pub fn mtu(&self) -> Result<u32, IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::get_mtu(&ctl_fd, &self.ifname)
+    }
+
+    /// MTU at the time of the lookup (`IFLA_MTU`)
+    #[cfg(target_os = "linux")]
+    pub fn mtu(&self) -> Result<u32, IfError> {
+        Ok(self.mtu)
+    }
+
+    pub fn set_mtu(&mut self, mtu: u32) -> Result<(), IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::set_mtu(&ctl_fd, &self.ifname, mtu)?;
+        #[cfg(target_os = "linux")]
+        {
+            self.mtu = mtu;
+        }
+        Ok(())
+    }
+
+    /// Fails with `IfError::MustBeDown`, if the driver can't change the address of the running iface
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn set_hw_addr(&mut self, hw_addr: MacAddress) -> Result<(), IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        impls::set_hw_addr(&ctl_fd, &self.ifname, hw_addr)?;
+        self.hw_addr = Some(hw_addr);
+        Ok(())
+    }
+
+    /// Renames the iface. Running iface is brought down for the time of renaming
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+    pub fn rename(&mut self, new_ifname: &str) -> Result<(), IfError> {
+        // validate the name before bringing the iface down
+        ifstructs::ifreq::from_name(new_ifname)?;
+
+        let ctl_fd = impls::new_control_socket()?;
+        // the iface without carrier must be brought down as well
+        let was_up = impls::set_admin_down(&ctl_fd, &self.ifname)?;
+        let res = impls::rename_iface(&ctl_fd, &self.ifname, new_ifname);
+        if res.is_ok() {
+            self.ifname = new_ifname.to_owned();
+        }
+        if was_up {
+            impls::up(&ctl_fd, &self.ifname)?;
+        }
+        res
+    }
+
     pub fn set_promiscuous_mode(&self, is_enable: bool) -> Result<(), IfError> {
         let ctl_fd = impls::new_control_socket()?;
         impls::set_promiscuous_mode(&ctl_fd, &self.ifname, is_enable)
     }
 
     pub fn refresh(&mut self) -> Result<(), IfError> {
-        let new_iface = Self::find_by_name(&self.ifname)?;
-        self.ip_addrs = new_iface.ip_addrs;
-        self.flags = new_iface.flags;
-        self.link = new_iface.link;
+        *self = Self::find_by_name(&self.ifname)?;
         Ok(())
     }
 
@@ -236,20 +582,251 @@ impl Iface {
 }
 #[cfg(target_os = "linux")]
 impl Iface {
-    fn is_bridge(ifname: &str) -> Result<bool, IfError> {
-        let ctl_fd = impls::new_control_socket()?;
-        let drv = impls::get_ethernet_driver(&ctl_fd, ifname)?;
-        Ok(drv.driver == "bridge")
+    pub fn all() -> Result<Vec<Iface>, IfError> {
+        use std::collections::HashMap;
+
+        let mut nl = impls::NetlinkSocket::new()?;
+        let addrs = impls::list_addrs(&mut nl)?;
+        let links = impls::list_links(&mut nl)?;
+        let names: HashMap<_, _> = links.iter().map(|l| (l.index, l.name.clone())).collect();
+        Ok(links
+            .into_iter()
+            .map(|link| {
+                let parent = link.local_parent().and_then(|i| names.get(&i).cloned());
+                Self::from_link(link, parent, &addrs)
+            })
+            .collect())
     }
-    fn is_tun(ifname: &str) -> Result<bool, IfError> {
-        let ctl_fd = impls::new_control_socket()?;
-        let drv = impls::get_ethernet_driver(&ctl_fd, ifname)?;
-        Ok(drv.driver == "tun" && drv.bus_info == "tun")
+
+    pub fn find_by_name(ifname: &str) -> Result<Iface, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        let link = impls::get_link_by_name(&mut nl, ifname)?;
+        // only vlan needs the name of its parent
+        let parent = match link.local_parent() {
+            Some(index) if link.kind.as_deref() == Some("vlan") => {
+                impls::get_link_by_index(&mut nl, index)
+                    .ok()
+                    .map(|l| l.name)
+            }
+            _ => None,
+        };
+        let addrs = impls::list_addrs(&mut nl)?;
+        Ok(Self::from_link(link, parent, &addrs))
     }
-    fn is_tap(ifname: &str) -> Result<bool, IfError> {
+
+    /// `parent` is the name of the `IFLA_LINK` interface, if it's in the same namespace
+    fn from_link(
+        link: impls::LinkRecord,
+        parent: Option<String>,
+        addrs: &[impls::AddrRecord],
+    ) -> Iface {
+        let link_type = match link.kind.as_deref() {
+            None if link.flags.contains(IfFlags::IFF_LOOPBACK) => Link::Loopback,
+            None if link.link_type == libc::ARPHRD_ETHER => Link::Ethernet,
+            None => Link::Regular,
+            // both are "tun" kind, only tap has ethernet header
+            Some("tun") if link.link_type == libc::ARPHRD_ETHER => Link::Tap,
+            Some("tun") => Link::Tun,
+            Some("bridge") => Link::Bridge,
+            Some("veth") => Link::Veth,
+            // the parent may be in another namespace
+            Some("vlan") => match parent.and_then(|p| impls::parse_vlan(&link, &p)) {
+                Some(vlan) => Link::Vlan {
+                    parent: vlan.parent,
+                    id: vlan.id,
+                },
+                None => Link::Other("vlan".to_owned()),
+            },
+            Some("macvlan") => Link::Macvlan,
+            Some("vxlan") => Link::Vxlan,
+            Some("bond") => Link::Bond,
+            Some("dummy") => Link::Dummy,
+            Some("wireguard") => Link::Wireguard,
+            Some("gre") | Some("gretap") | Some("ip6gre") | Some("ip6gretap") => Link::Gre,
+            Some("vrf") => Link::Vrf,
+            Some(kind) => Link::Other(kind.to_owned()),
+        };
+
+        let addrs: Vec<IfaceAddr> = addrs
+            .iter()
+            .filter(|a| a.index == link.index)
+            .filter_map(|a| {
+                Some(IfaceAddr {
+                    network: ipnetwork::IpNetwork::new(a.addr, a.prefix_len).ok()?,
+                    scope: Scope::from(a.scope),
+                    flags: a.flags,
+                })
+            })
+            .collect();
+
+        Iface {
+            ip_addrs: addrs.iter().map(|a| a.network.ip()).collect(),
+            hw_addr: link.hw_addr,
+            ifname: link.name,
+            flags: link.flags,
+            link: link_type,
+            index: link.index as u32,
+            mtu: link.mtu,
+            addrs,
+            operstate: OperState::from(link.operstate),
+            link_type: link.link_type,
+            txqueuelen: link.txqueuelen,
+            qdisc: link.qdisc,
+            master_index: link.master.map(|i| i as u32),
+            parent_index: link.parent.map(|i| i as u32),
+        }
+    }
+
+    /// Interface index, as used by the kernel to refer to the interface
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Addresses along with their prefix lengths, scopes and flags
+    pub fn addrs(&self) -> &[IfaceAddr] {
+        &self.addrs
+    }
+
+    pub fn operstate(&self) -> OperState {
+        self.operstate
+    }
+
+    /// `ARPHRD_*` hardware type, e.g. `libc::ARPHRD_ETHER` or `libc::ARPHRD_NONE` for tun
+    pub fn link_type(&self) -> u16 {
+        self.link_type
+    }
+
+    /// Length of the transmit queue in packets
+    pub fn txqueuelen(&self) -> u32 {
+        self.txqueuelen
+    }
+
+    /// Name of the root queueing discipline, e.g. "noqueue" or "fq_codel"
+    pub fn qdisc(&self) -> Option<&str> {
+        self.qdisc.as_deref()
+    }
+
+    /// Index of the bridge, bond or VRF, this interface is enslaved to
+    pub fn master_index(&self) -> Option<u32> {
+        self.master_index
+    }
+
+    /// Name and bus of the driver (`ETHTOOL_GDRVINFO`)
+    pub fn driver_info(&self) -> Result<DriverInfo, IfError> {
         let ctl_fd = impls::new_control_socket()?;
-        let drv = impls::get_ethernet_driver(&ctl_fd, ifname)?;
-        Ok(drv.driver == "tun" && drv.bus_info == "tap")
+        impls::get_ethernet_driver(&ctl_fd, &self.ifname)
+    }
+
+    /// Index of the interface, this one is stacked on (vlan, macvlan), or of the veth peer
+    pub fn parent_index(&self) -> Option<u32> {
+        self.parent_index
+    }
+
+    pub fn add_addr(&mut self, cidr: ipnetwork::IpNetwork) -> Result<(), IfError> {
+        self.add_addr_with_options(cidr, &AddrOptions::default())
+    }
+
+    pub fn add_addr_with_options(
+        &mut self,
+        cidr: ipnetwork::IpNetwork,
+        opts: &AddrOptions,
+    ) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        let link = impls::get_link_by_name(&mut nl, &self.ifname)?;
+
+        impls::add_addr(&mut nl, link.index, cidr, opts)?;
+
+        self.refresh()?;
+
+        Ok(())
+    }
+
+    pub fn del_addr(&mut self, ip: IpAddr) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        let link = impls::get_link_by_name(&mut nl, &self.ifname)?;
+        let addr = impls::list_addrs(&mut nl)?
+            .into_iter()
+            .find(|a| a.index == link.index && a.addr == ip)
+            .ok_or(IfError::NotFound)?;
+
+        impls::del_addr(&mut nl, link.index, ip, addr.prefix_len)?;
+
+        self.refresh()?;
+
+        Ok(())
+    }
+
+    /// Creates the interface of the `kind`, e.g. "dummy", "ifb" or "bridge", which doesn't need
+    /// any kind-specific settings. It is left down
+    pub fn create(ifname: &str, kind: &str, opts: &LinkOptions) -> Result<Iface, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::create_link(&mut nl, ifname, kind, opts)?;
+        Self::find_by_name(ifname)
+    }
+
+    /// Removes the interface. Both ends of the veth pair are removed
+    pub fn delete(self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        let link = impls::get_link_by_name(&mut nl, &self.ifname)?;
+        impls::del_link(&mut nl, link.index)
+    }
+
+    /// Moves the interface into the network namespace (`ip link set <name> netns <netns>`)
+    pub fn move_to_netns(self, netns: &NetNs) -> Result<(), IfError> {
+        use std::os::unix::io::AsRawFd;
+
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::set_link_netns(&mut nl, &self.ifname, netns.as_raw_fd())
+    }
+
+    /// ARP/NDP entries of this interface
+    pub fn neighbors(&self) -> Result<Vec<Neighbor>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        Ok(impls::list_neighbors(&mut nl)?
+            .into_iter()
+            .filter(|n| n.iface == self.ifname)
+            .collect())
+    }
+
+    /// Forwarding database entries of this interface
+    pub fn fdb_entries(&self) -> Result<Vec<FdbEntry>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        Ok(impls::list_fdb(&mut nl)?
+            .into_iter()
+            .filter(|e| e.iface == self.ifname)
+            .collect())
+    }
+
+    /// Current counters of the interface (`IFLA_STATS64`)
+    pub fn stats(&self) -> Result<IfaceStats, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        let link = impls::get_link_by_name(&mut nl, &self.ifname)?;
+        link.stats.map(IfaceStats::from).ok_or_else(|| {
+            IfError::from(::std::io::Error::new(
+                ::std::io::ErrorKind::InvalidData,
+                "no IFLA_STATS64 in the link message",
+            ))
+        })
+    }
+
+    /// Name of the bridge, bond or VRF, this interface is enslaved to
+    pub fn master(&self) -> Result<Option<String>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        match impls::get_link_by_name(&mut nl, &self.ifname)?.master {
+            Some(master) => Ok(Some(impls::get_link_by_index(&mut nl, master)?.name)),
+            None => Ok(None),
+        }
+    }
+
+    /// Names of the interfaces enslaved to this one
+    pub fn slaves(&self) -> Result<Vec<String>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        let index = impls::get_link_by_name(&mut nl, &self.ifname)?.index;
+        Ok(impls::list_links(&mut nl)?
+            .into_iter()
+            .filter(|l| l.master == Some(index))
+            .map(|l| l.name)
+            .collect())
     }
 
     pub fn bind_to_device<S: std::os::unix::io::AsRawFd>(
@@ -290,6 +867,21 @@ mod tests {
     #[cfg(not(any(target_os = "macos", target_os = "ios")))]
     const DEFAULT_IFACE: &str = "lo";
 
+    /// Reports the test as skipped, if the kernel doesn't support the kind of the interface.
+    /// With `IFCONTROL_TEST_ALL_KINDS` set, nothing is skipped and the error fails the test
+    #[cfg(target_os = "linux")]
+    pub(crate) fn skip_unsupported<T>(kind: &str, res: &Result<T, IfError>) -> bool {
+        match *res {
+            Err(IfError::Nix(::nix::Error::Sys(::nix::errno::Errno::EOPNOTSUPP)))
+                if ::std::env::var_os("IFCONTROL_TEST_ALL_KINDS").is_none() =>
+            {
+                eprintln!("skipped: kernel doesn't support {} interfaces", kind);
+                true
+            }
+            _ => false,
+        }
+    }
+
     #[test]
     fn test_not_found() {
         match Iface::find_by_name("not_exist") {
@@ -330,8 +922,267 @@ mod tests {
     //     iface.set_promiscuous_mode(true).unwrap();
     // }
 
+    #[test]
+    fn test_mtu() {
+        let iface = Iface::find_by_name(DEFAULT_IFACE).unwrap();
+        assert!(iface.mtu().unwrap() > 0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_stats() {
+        let iface = Iface::find_by_name("lo").unwrap();
+        let before = iface.stats().expect("get stats");
+
+        let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket.send_to(b"stats", socket.local_addr().unwrap()).unwrap();
+
+        let after = iface.stats().expect("get stats");
+        assert!(after.rx_packets > before.rx_packets);
+        assert!(after.tx_bytes > before.tx_bytes);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_veth_stats() {
+        let mut iface = Veth::new("ifctlstat0", "ifctlstat1")
+            .create()
+            .expect("create veth");
+        let peer = Iface::find_by_name("ifctlstat1").unwrap();
+        iface
+            .add_addr("10.254.11.1/24".parse().unwrap())
+            .expect("add addr");
+        iface.up().unwrap();
+        peer.up().unwrap();
+        let before = (iface.stats().unwrap(), peer.stats().unwrap());
+
+        // ARP request for the unanswered peer address goes over the pair
+        let socket = std::net::UdpSocket::bind("10.254.11.1:0").unwrap();
+        socket.send_to(b"stats", "10.254.11.2:9").unwrap();
+
+        let after = (iface.stats().unwrap(), peer.stats().unwrap());
+        assert!(after.0.tx_packets > before.0.tx_packets);
+        assert!(after.1.rx_packets > before.1.rx_packets);
+        assert!(after.1.rx_bytes > before.1.rx_bytes);
+
+        iface.delete().expect("delete veth");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_create_delete() {
+        let opts = LinkOptions {
+            mtu: Some(1400),
+            hw_addr: Some(MacAddress::parse_str("02:00:00:00:17:01").unwrap()),
+        };
+        let ifb = Iface::create("ifctlifb0", "ifb", &opts).expect("create ifb");
+        assert_eq!(ifb.hw_addr(), opts.hw_addr);
+        assert_eq!(ifb.mtu().unwrap(), 1400);
+        ifb.delete().expect("delete ifb");
+        match Iface::find_by_name("ifctlifb0") {
+            Err(IfError::NotFound) => {}
+            r => panic!("ifb is not removed: {:?}", r),
+        }
+
+        let created = Iface::create("ifctldummy0", "dummy", &LinkOptions::default());
+        if !skip_unsupported("dummy", &created) {
+            let dummy = created.expect("create dummy");
+            assert_eq!(dummy.link(), &Link::Dummy);
+            dummy.delete().expect("delete dummy");
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_set_mtu() {
+        let ctl_fd = impls::new_control_socket().unwrap();
+        impls::create_bridge(&ctl_fd, "ifctlmtu0").expect("create bridge");
+
+        let mut iface = Iface::find_by_name("ifctlmtu0").unwrap();
+        iface.set_mtu(1420).expect("set mtu");
+        assert_eq!(iface.mtu().unwrap(), 1420);
+        let iface = Iface::find_by_name("ifctlmtu0").unwrap();
+        assert_eq!(iface.mtu().unwrap(), 1420);
+
+        impls::remove_bridge(&ctl_fd, "ifctlmtu0").expect("remove bridge");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_set_hw_addr() {
+        let ctl_fd = impls::new_control_socket().unwrap();
+        impls::create_bridge(&ctl_fd, "ifctlhw0").expect("create bridge");
+
+        let hw_addr = MacAddress::parse_str("02:00:00:00:21:01").unwrap();
+        let mut iface = Iface::find_by_name("ifctlhw0").unwrap();
+        iface.set_hw_addr(hw_addr).expect("set hw addr");
+        assert_eq!(iface.hw_addr(), Some(hw_addr));
+        assert_eq!(Iface::find_by_name("ifctlhw0").unwrap().hw_addr(), Some(hw_addr));
+
+        impls::remove_bridge(&ctl_fd, "ifctlhw0").expect("remove bridge");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_rename() {
+        let ctl_fd = impls::new_control_socket().unwrap();
+        impls::create_bridge(&ctl_fd, "ifctlname0").expect("create bridge");
+
+        let mut iface = Iface::find_by_name("ifctlname0").unwrap();
+        iface.up().unwrap();
+        assert!(iface.rename("ifctl-name-is-too-long").is_err());
+        iface.rename("ifctlname1").expect("rename");
+        assert_eq!(iface.name(), "ifctlname1");
+        assert!(Iface::find_by_name("ifctlname1").is_ok());
+        match Iface::find_by_name("ifctlname0") {
+            Err(IfError::NotFound) => {}
+            _ => panic!("old name still exists"),
+        }
+        let req = impls::get_iface_ifreq(&ctl_fd, "ifctlname1").unwrap();
+        assert!(unsafe { req.get_flags() }.contains(IfFlags::IFF_UP));
+
+        iface.down().unwrap();
+        impls::remove_bridge(&ctl_fd, "ifctlname1").expect("remove bridge");
+    }
+
     #[test]
     fn test_list_all() {
         println!("Ifaces: {:?}", Iface::all().unwrap());
     }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_add_del_addr() {
+        let ctl_fd = impls::new_control_socket().unwrap();
+        impls::create_bridge(&ctl_fd, "ifctladdr0").expect("create bridge");
+
+        let mut iface = Iface::find_by_name("ifctladdr0").unwrap();
+        let v4: ipnetwork::IpNetwork = "10.254.17.1/24".parse().unwrap();
+        let v6: ipnetwork::IpNetwork = "fd00:254:17::1/64".parse().unwrap();
+        iface.add_addr(v4).expect("add ipv4");
+        iface
+            .add_addr_with_options(
+                v6,
+                &AddrOptions {
+                    valid_lifetime: Some(3600),
+                    ..Default::default()
+                },
+            )
+            .expect("add ipv6");
+        assert!(iface.ip_addresses().any(|ip| ip == v4.ip()));
+        assert!(iface.ip_addresses().any(|ip| ip == v6.ip()));
+        let added = iface
+            .addrs()
+            .iter()
+            .find(|a| a.network == v4)
+            .expect("ipv4 with prefix");
+        assert_eq!(added.scope, Scope::Universe);
+        assert!(added.flags.contains(IfaFlags::IFA_F_PERMANENT));
+        let added = iface
+            .addrs()
+            .iter()
+            .find(|a| a.network == v6)
+            .expect("ipv6 with prefix");
+        assert!(!added.flags.contains(IfaFlags::IFA_F_PERMANENT));
+
+        iface.del_addr(v4.ip()).expect("del ipv4");
+        iface.del_addr(v6.ip()).expect("del ipv6");
+        assert!(!iface.ip_addresses().any(|ip| ip == v4.ip() || ip == v6.ip()));
+
+        impls::remove_bridge(&ctl_fd, "ifctladdr0").expect("remove bridge");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_details() {
+        let lo = Iface::find_by_name("lo").unwrap();
+        assert!(lo.index() > 0);
+        assert_eq!(lo.link_type(), libc::ARPHRD_LOOPBACK);
+        assert_eq!(lo.operstate(), OperState::Unknown);
+        assert_eq!(lo.master_index(), None);
+        assert_eq!(lo.parent_index(), None);
+        assert!(lo.addrs().contains(&IfaceAddr {
+            network: "127.0.0.1/8".parse().unwrap(),
+            scope: Scope::Host,
+            flags: IfaFlags::IFA_F_PERMANENT,
+        }));
+
+        let mut veth = Veth::new("ifctldet0", "ifctldet1");
+        veth.mtu = Some(1450);
+        let iface = veth.create().expect("create veth");
+        let bridge = Bridge::create("ifctldet2").expect("create bridge");
+        bridge.add_port("ifctldet0").expect("add port");
+
+        let iface = Iface::find_by_name(iface.name()).unwrap();
+        let peer = Iface::find_by_name("ifctldet1").unwrap();
+        let bridge_iface = Iface::find_by_name("ifctldet2").unwrap();
+        assert_eq!(iface.link_type(), libc::ARPHRD_ETHER);
+        assert_eq!(iface.operstate(), OperState::Down);
+        assert_eq!(iface.mtu().unwrap(), 1450);
+        assert_eq!(iface.parent_index(), Some(peer.index()));
+        assert_eq!(peer.parent_index(), Some(iface.index()));
+        assert_eq!(iface.master_index(), Some(bridge_iface.index()));
+        assert_eq!(peer.master_index(), None);
+        assert!(iface.txqueuelen() > 0);
+        assert!(iface.qdisc().is_some());
+
+        bridge.delete().expect("delete bridge");
+        iface.delete().expect("delete veth");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_foreign_vlan_parent() {
+        let link = impls::LinkRecord {
+            index: 1000,
+            name: "ifctlvlan9".to_owned(),
+            flags: IfFlags::empty(),
+            link_type: libc::ARPHRD_ETHER,
+            operstate: ifstructs::IF_OPER_DOWN,
+            hw_addr: None,
+            mtu: 1500,
+            txqueuelen: 1000,
+            qdisc: None,
+            stats: None,
+            parent: Some(1),
+            link_netnsid: Some(0),
+            master: None,
+            kind: Some("vlan".to_owned()),
+            info_data: vec![],
+            slave_kind: None,
+            slave_info_data: vec![],
+        };
+        // index 1 belongs to the other namespace, it must not be resolved locally
+        assert_eq!(link.local_parent(), None);
+        let iface = Iface::from_link(link, None, &[]);
+        assert_eq!(iface.link(), &Link::Other("vlan".to_owned()));
+        assert_eq!(iface.parent_index, Some(1));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_link_kinds() {
+        assert_eq!(Iface::find_by_name("lo").unwrap().link(), &Link::Loopback);
+
+        let veth = Veth::new("ifctlkind0", "ifctlkind1")
+            .create()
+            .expect("create veth");
+        assert_eq!(veth.link(), &Link::Veth);
+        assert_eq!(veth.driver_info().unwrap().driver, "veth");
+
+        let bridge = Bridge::create("ifctlkind2").expect("create bridge");
+        assert_eq!(
+            Iface::find_by_name(bridge.name()).unwrap().link(),
+            &Link::Bridge
+        );
+        let ifb = Iface::create("ifctlkind3", "ifb", &Default::default()).expect("create ifb");
+        assert_eq!(ifb.link(), &Link::Other("ifb".to_owned()));
+        let vxlan = Vxlan::new("ifctlkind4", 22).create().expect("create vxlan");
+        assert_eq!(vxlan.link(), &Link::Vxlan);
+
+        vxlan.delete().expect("delete vxlan");
+        ifb.delete().expect("delete ifb");
+        bridge.delete().expect("delete bridge");
+        veth.delete().expect("delete veth");
+    }
 }
@@ -0,0 +1,141 @@
+use eui48::MacAddress;
+use impls;
+use {IfError, Iface};
+
+/// How macvlan interfaces of the same parent communicate with each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MacvlanMode {
+    /// No communication with other macvlans of the parent
+    Private,
+    /// Through the external switch, which sends frames back (the kernel default)
+    Vepa,
+    /// Directly, without sending frames out of the parent
+    Bridge,
+    /// The only child, which takes over the parent
+    Passthru,
+    /// Mode, unknown to this crate, e.g. source of the newer kernels
+    Other(u32),
+}
+
+/// Child interface with its own MAC address on the parent
+/// (`ip link add link <parent> name <name> type macvlan mode <mode>`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Macvlan {
+    pub name: String,
+    /// Name of the parent interface
+    pub parent: String,
+    pub mode: MacvlanMode,
+    /// Random address is generated by the kernel by default
+    pub hw_addr: Option<MacAddress>,
+}
+
+impl Macvlan {
+    pub fn new(name: &str, parent: &str, mode: MacvlanMode) -> Macvlan {
+        Macvlan {
+            name: name.to_owned(),
+            parent: parent.to_owned(),
+            mode,
+            hw_addr: None,
+        }
+    }
+
+    /// Settings of the existing macvlan interface. `IfError::NotFound` if it is not a macvlan
+    pub fn find_by_name(ifname: &str) -> Result<Macvlan, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::get_macvlan(&mut nl, ifname)
+    }
+
+    /// Creates the interface, which is down. It is removed by `Iface::delete`
+    pub fn create(&self) -> Result<Iface, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::create_macvlan(&mut nl, self)?;
+        Iface::find_by_name(&self.name)
+    }
+}
+
+/// Layer, at which ipvlan interfaces share the parent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpvlanMode {
+    L2,
+    /// The kernel default
+    L3,
+    /// Same as `L3`, but passes traffic through netfilter of the parent namespace
+    L3S,
+    /// Mode, unknown to this crate
+    Other(u16),
+}
+
+/// Child interface sharing the MAC address of the parent
+/// (`ip link add link <parent> name <name> type ipvlan mode <mode>`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ipvlan {
+    pub name: String,
+    /// Name of the parent interface
+    pub parent: String,
+    pub mode: IpvlanMode,
+}
+
+impl Ipvlan {
+    pub fn new(name: &str, parent: &str, mode: IpvlanMode) -> Ipvlan {
+        Ipvlan {
+            name: name.to_owned(),
+            parent: parent.to_owned(),
+            mode,
+        }
+    }
+
+    /// Settings of the existing ipvlan interface. `IfError::NotFound` if it is not an ipvlan
+    pub fn find_by_name(ifname: &str) -> Result<Ipvlan, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::get_ipvlan(&mut nl, ifname)
+    }
+
+    /// Creates the interface, which is down. It is removed by `Iface::delete`
+    pub fn create(&self) -> Result<Iface, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::create_ipvlan(&mut nl, self)?;
+        Iface::find_by_name(&self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tests::skip_unsupported;
+    use Veth;
+
+    #[test]
+    fn test_macvlan() {
+        let parent = Veth::new("ifctlmacv0", "ifctlmacv1")
+            .create()
+            .expect("create veth");
+
+        let mut macvlan = Macvlan::new("ifctlmacv2", "ifctlmacv0", MacvlanMode::Bridge);
+        macvlan.hw_addr = Some(MacAddress::parse_str("02:00:00:00:14:02").unwrap());
+        let created = macvlan.create();
+        if !skip_unsupported("macvlan", &created) {
+            let iface = created.expect("create macvlan");
+            assert_eq!(Macvlan::find_by_name("ifctlmacv2").unwrap(), macvlan);
+            iface.delete().expect("delete macvlan");
+        }
+
+        parent.delete().expect("delete veth");
+    }
+
+    #[test]
+    fn test_ipvlan() {
+        let parent = Veth::new("ifctlipv0", "ifctlipv1")
+            .create()
+            .expect("create veth");
+
+        let ipvlan = Ipvlan::new("ifctlipv2", "ifctlipv0", IpvlanMode::L2);
+        let created = ipvlan.create();
+        if !skip_unsupported("ipvlan", &created) {
+            let iface = created.expect("create ipvlan");
+            assert_eq!(Ipvlan::find_by_name("ifctlipv2").unwrap(), ipvlan);
+            iface.delete().expect("delete ipvlan");
+        }
+
+        parent.delete().expect("delete veth");
+    }
+}
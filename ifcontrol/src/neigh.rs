@@ -0,0 +1,118 @@
+use eui48::MacAddress;
+pub use ifstructs::{NtfFlags, NudState};
+use impls;
+use std::net::IpAddr;
+use {IfError, IpFamily};
+
+/// ARP (IPv4) or NDP (IPv6) neighbor cache entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct Neighbor {
+    /// Name of the interface
+    pub iface: String,
+    pub ip: IpAddr,
+    /// Link-layer address. Proxy and incomplete entries don't have it
+    pub lladdr: Option<MacAddress>,
+    pub state: NudState,
+    pub flags: NtfFlags,
+}
+
+impl Neighbor {
+    /// Permanent (static) entry
+    pub fn new(iface: &str, ip: IpAddr, lladdr: MacAddress) -> Neighbor {
+        Neighbor {
+            iface: iface.to_owned(),
+            ip,
+            lladdr: Some(lladdr),
+            state: NudState::NUD_PERMANENT,
+            flags: NtfFlags::empty(),
+        }
+    }
+
+    /// Proxy ARP/NDP entry (`ip neigh add proxy ...`)
+    pub fn new_proxy(iface: &str, ip: IpAddr) -> Neighbor {
+        Neighbor {
+            iface: iface.to_owned(),
+            ip,
+            lladdr: None,
+            state: NudState::empty(),
+            flags: NtfFlags::NTF_PROXY,
+        }
+    }
+
+    pub fn family(&self) -> IpFamily {
+        match self.ip {
+            IpAddr::V4(_) => IpFamily::V4,
+            IpAddr::V6(_) => IpFamily::V6,
+        }
+    }
+
+    /// Entries of all interfaces, including proxy ones
+    pub fn list() -> Result<Vec<Neighbor>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::list_neighbors(&mut nl)
+    }
+
+    pub fn add(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::add_neighbor(&mut nl, self)
+    }
+
+    /// Adds the entry or replaces the existing one with the same IP address
+    pub fn replace(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::replace_neighbor(&mut nl, self)
+    }
+
+    pub fn delete(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::del_neighbor(&mut nl, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Iface;
+
+    #[test]
+    fn test_add_replace_delete() {
+        let ctl_fd = impls::new_control_socket().unwrap();
+        impls::create_bridge(&ctl_fd, "ifctlneigh0").expect("create bridge");
+        let iface = Iface::find_by_name("ifctlneigh0").unwrap();
+        iface.up().unwrap();
+
+        let mut v4 = Neighbor::new(
+            "ifctlneigh0",
+            "10.254.19.2".parse().unwrap(),
+            MacAddress::parse_str("02:00:00:00:19:02").unwrap(),
+        );
+        let v6 = Neighbor::new(
+            "ifctlneigh0",
+            "fd00:254:19::2".parse().unwrap(),
+            MacAddress::parse_str("02:00:00:00:19:03").unwrap(),
+        );
+        let proxy = Neighbor::new_proxy("ifctlneigh0", "10.254.19.3".parse().unwrap());
+        v4.add().expect("add ipv4 neighbor");
+        v6.add().expect("add ipv6 neighbor");
+        proxy.add().expect("add proxy neighbor");
+
+        let neighbors = iface.neighbors().expect("list neighbors");
+        assert!(neighbors.contains(&v4));
+        assert!(neighbors.contains(&v6));
+        assert!(neighbors
+            .iter()
+            .any(|n| n.ip == proxy.ip && n.flags.contains(NtfFlags::NTF_PROXY)));
+
+        v4.lladdr = Some(MacAddress::parse_str("02:00:00:00:19:04").unwrap());
+        v4.replace().expect("replace ipv4 neighbor");
+        assert!(iface.neighbors().unwrap().contains(&v4));
+
+        v4.delete().expect("delete ipv4 neighbor");
+        v6.delete().expect("delete ipv6 neighbor");
+        proxy.delete().expect("delete proxy neighbor");
+        assert!(iface.neighbors().unwrap().is_empty());
+
+        iface.down().unwrap();
+        impls::remove_bridge(&ctl_fd, "ifctlneigh0").expect("remove bridge");
+    }
+}
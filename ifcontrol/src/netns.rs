@@ -0,0 +1,193 @@
+use impls;
+use std::fs::File;
+use std::os::unix::fs::MetadataExt;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::path::{Path, PathBuf};
+use IfError;
+
+/// Directory of the named namespaces, shared with iproute2 (`ip netns`)
+const NETNS_RUN_DIR: &str = "/var/run/netns";
+
+/// Network namespace handle. The namespace is kept alive while the handle is open,
+/// named ones are kept until `delete`
+#[derive(Debug)]
+pub struct NetNs {
+    file: File,
+    name: Option<String>,
+}
+
+/// Switches the thread back into the saved namespace, even if the closure panics
+struct Restore(Option<File>);
+
+impl Restore {
+    fn restore(mut self) -> Result<(), IfError> {
+        match self.0.take() {
+            Some(file) => impls::set_netns(&file),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        // only reached, if the closure panics. Panicking once more would abort the process
+        if let Some(ref file) = self.0 {
+            let _ = impls::set_netns(file);
+        }
+    }
+}
+
+impl NetNs {
+    /// New anonymous namespace (`unshare --net`). It is destroyed with the last handle to it
+    /// and the last process in it
+    pub fn new() -> Result<NetNs, IfError> {
+        Ok(NetNs {
+            file: impls::new_netns()?,
+            name: None,
+        })
+    }
+
+    /// New persistent namespace, visible to `ip netns` (`ip netns add <name>`)
+    pub fn create(name: &str) -> Result<NetNs, IfError> {
+        impls::new_named_netns(&Self::path_of(name))?;
+        Self::open(name)
+    }
+
+    /// Existing namespace from `/var/run/netns/<name>`
+    pub fn open(name: &str) -> Result<NetNs, IfError> {
+        Ok(NetNs {
+            file: File::open(Self::path_of(name))?,
+            name: Some(name.to_owned()),
+        })
+    }
+
+    /// Namespace of the process (`/proc/<pid>/ns/net`)
+    pub fn from_pid(pid: u32) -> Result<NetNs, IfError> {
+        Self::from_path(format!("/proc/{}/ns/net", pid))
+    }
+
+    /// Namespace, referred by the file, e.g. bind-mounted by container runtime
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<NetNs, IfError> {
+        Ok(NetNs {
+            file: File::open(path)?,
+            name: None,
+        })
+    }
+
+    /// Namespace of the calling thread
+    pub fn current() -> Result<NetNs, IfError> {
+        Ok(NetNs {
+            file: impls::current_netns()?,
+            name: None,
+        })
+    }
+
+    /// Name in `/var/run/netns`, if it is a named namespace
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Runs `f` with the calling thread switched into this namespace, and switches it back
+    /// afterwards. Sockets and tun/tap devices created by `f` stay in this namespace, e.g.
+    /// `netns.run(|| Native::new().create_tun(None, 1))` creates a tun inside of it.
+    pub fn run<T, F: FnOnce() -> T>(&self, f: F) -> Result<T, IfError> {
+        let restore = Restore(Some(impls::current_netns()?));
+        impls::set_netns(&self.file)?;
+        let res = f();
+        restore.restore()?;
+        Ok(res)
+    }
+
+    /// Removes the name of the namespace (`ip netns delete <name>`). The namespace itself is
+    /// destroyed once it has no processes and open handles. Anonymous ones are just closed
+    pub fn delete(self) -> Result<(), IfError> {
+        match self.name {
+            Some(ref name) => impls::del_named_netns(&Self::path_of(name)),
+            None => Ok(()),
+        }
+    }
+
+    fn path_of(name: &str) -> PathBuf {
+        Path::new(NETNS_RUN_DIR).join(name)
+    }
+}
+
+/// Handles are equal, if they refer to the same namespace
+impl PartialEq for NetNs {
+    fn eq(&self, other: &NetNs) -> bool {
+        match (self.file.metadata(), other.file.metadata()) {
+            (Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+            _ => false,
+        }
+    }
+}
+
+impl AsRawFd for NetNs {
+    fn as_raw_fd(&self) -> RawFd {
+        self.file.as_raw_fd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use {Iface, Veth};
+
+    fn inode(netns: &NetNs) -> u64 {
+        netns.file.metadata().unwrap().ino()
+    }
+
+    #[test]
+    fn test_anonymous() {
+        let current = NetNs::current().unwrap();
+        let netns = NetNs::new().expect("create netns");
+        assert_ne!(inode(&current), inode(&netns));
+        assert_ne!(current, netns);
+        assert_eq!(NetNs::current().unwrap(), current);
+        assert_eq!(
+            inode(&NetNs::from_pid(::std::process::id()).unwrap()),
+            inode(&current)
+        );
+
+        let iface = Veth::new("ifctlns0", "ifctlns1")
+            .create()
+            .expect("create veth");
+        iface.move_to_netns(&netns).expect("move to netns");
+        assert!(Iface::find_by_name("ifctlns0").is_err());
+
+        let (found, netns_inode) = netns
+            .run(|| {
+                let iface = Iface::find_by_name("ifctlns0");
+                (iface.is_ok(), inode(&NetNs::current().unwrap()))
+            })
+            .unwrap();
+        assert!(found);
+        assert_eq!(netns_inode, inode(&netns));
+        // the thread is switched back
+        assert_eq!(inode(&NetNs::current().unwrap()), inode(&current));
+
+        Iface::find_by_name("ifctlns1")
+            .unwrap()
+            .delete()
+            .expect("delete veth");
+    }
+
+    #[test]
+    fn test_named() {
+        let netns = NetNs::create("ifctlns2").expect("create netns");
+        assert_eq!(netns.name(), Some("ifctlns2"));
+        assert!(fs::metadata("/var/run/netns/ifctlns2").is_ok());
+        assert_eq!(inode(&NetNs::open("ifctlns2").unwrap()), inode(&netns));
+        assert!(NetNs::create("ifctlns2").is_err());
+
+        netns
+            .run(|| Iface::create("ifctlns3", "ifb", &Default::default()))
+            .unwrap()
+            .expect("create iface in netns");
+        assert!(Iface::find_by_name("ifctlns3").is_err());
+
+        netns.delete().expect("delete netns");
+        assert!(NetNs::open("ifctlns2").is_err());
+    }
+}
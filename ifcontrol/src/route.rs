@@ -0,0 +1,323 @@
+#[cfg(target_os = "linux")]
+use ifstructs;
+use impls;
+use ipnetwork::IpNetwork;
+use std::net::IpAddr;
+use {IfError, Scope};
+
+/// Type of the route (`RTN_*`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteType {
+    Unicast,
+    Local,
+    Broadcast,
+    Anycast,
+    Multicast,
+    Blackhole,
+    Unreachable,
+    Prohibit,
+    Throw,
+    Nat,
+    Other(u8),
+}
+
+#[cfg(target_os = "linux")]
+impl From<u8> for RouteType {
+    fn from(v: u8) -> RouteType {
+        match v {
+            ifstructs::RTN_UNICAST => RouteType::Unicast,
+            ifstructs::RTN_LOCAL => RouteType::Local,
+            ifstructs::RTN_BROADCAST => RouteType::Broadcast,
+            ifstructs::RTN_ANYCAST => RouteType::Anycast,
+            ifstructs::RTN_MULTICAST => RouteType::Multicast,
+            ifstructs::RTN_BLACKHOLE => RouteType::Blackhole,
+            ifstructs::RTN_UNREACHABLE => RouteType::Unreachable,
+            ifstructs::RTN_PROHIBIT => RouteType::Prohibit,
+            ifstructs::RTN_THROW => RouteType::Throw,
+            ifstructs::RTN_NAT => RouteType::Nat,
+            v => RouteType::Other(v),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl From<RouteType> for u8 {
+    fn from(t: RouteType) -> u8 {
+        match t {
+            RouteType::Unicast => ifstructs::RTN_UNICAST,
+            RouteType::Local => ifstructs::RTN_LOCAL,
+            RouteType::Broadcast => ifstructs::RTN_BROADCAST,
+            RouteType::Anycast => ifstructs::RTN_ANYCAST,
+            RouteType::Multicast => ifstructs::RTN_MULTICAST,
+            RouteType::Blackhole => ifstructs::RTN_BLACKHOLE,
+            RouteType::Unreachable => ifstructs::RTN_UNREACHABLE,
+            RouteType::Prohibit => ifstructs::RTN_PROHIBIT,
+            RouteType::Throw => ifstructs::RTN_THROW,
+            RouteType::Nat => ifstructs::RTN_NAT,
+            RouteType::Other(v) => v,
+        }
+    }
+}
+
+/// Origin of the route (`RTPROT_*`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteProtocol {
+    Unspec,
+    Redirect,
+    Kernel,
+    Boot,
+    Static,
+    Ra,
+    Dhcp,
+    Other(u8),
+}
+
+#[cfg(target_os = "linux")]
+impl From<u8> for RouteProtocol {
+    fn from(v: u8) -> RouteProtocol {
+        match v {
+            ifstructs::RTPROT_UNSPEC => RouteProtocol::Unspec,
+            ifstructs::RTPROT_REDIRECT => RouteProtocol::Redirect,
+            ifstructs::RTPROT_KERNEL => RouteProtocol::Kernel,
+            ifstructs::RTPROT_BOOT => RouteProtocol::Boot,
+            ifstructs::RTPROT_STATIC => RouteProtocol::Static,
+            ifstructs::RTPROT_RA => RouteProtocol::Ra,
+            ifstructs::RTPROT_DHCP => RouteProtocol::Dhcp,
+            v => RouteProtocol::Other(v),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl From<RouteProtocol> for u8 {
+    fn from(p: RouteProtocol) -> u8 {
+        match p {
+            RouteProtocol::Unspec => ifstructs::RTPROT_UNSPEC,
+            RouteProtocol::Redirect => ifstructs::RTPROT_REDIRECT,
+            RouteProtocol::Kernel => ifstructs::RTPROT_KERNEL,
+            RouteProtocol::Boot => ifstructs::RTPROT_BOOT,
+            RouteProtocol::Static => ifstructs::RTPROT_STATIC,
+            RouteProtocol::Ra => ifstructs::RTPROT_RA,
+            RouteProtocol::Dhcp => ifstructs::RTPROT_DHCP,
+            RouteProtocol::Other(v) => v,
+        }
+    }
+}
+
+/// Path of the multipath (ECMP) route
+#[derive(Debug, Clone, PartialEq)]
+pub struct NextHop {
+    pub gateway: Option<IpAddr>,
+    /// Name of the output interface
+    pub iface: Option<String>,
+    /// Share of the traffic relative to the other paths, from 1 to 256
+    pub weight: u16,
+}
+
+impl NextHop {
+    /// Path via the gateway with the weight 1
+    pub fn new(gateway: IpAddr) -> NextHop {
+        NextHop {
+            gateway: Some(gateway),
+            iface: None,
+            weight: 1,
+        }
+    }
+}
+
+/// IPv4 or IPv6 routing table entry. On FreeBSD routes can only be listed, `add`, `replace`
+/// and `delete` are Linux-only
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    pub destination: IpNetwork,
+    pub gateway: Option<IpAddr>,
+    /// Name of the output interface
+    pub iface: Option<String>,
+    /// Paths of the multipath route, `gateway` and `iface` are not set then. Linux-only
+    pub nexthops: Vec<NextHop>,
+    /// Preferred source address
+    pub source: Option<IpAddr>,
+    pub metric: Option<u32>,
+    pub table: u32,
+    pub protocol: RouteProtocol,
+    /// If not set, derived from the route type and gateway the same way as `ip route` does
+    pub scope: Option<Scope>,
+    pub kind: RouteType,
+}
+
+impl Route {
+    #[cfg(target_os = "linux")]
+    pub const TABLE_DEFAULT: u32 = ifstructs::RT_TABLE_DEFAULT;
+    #[cfg(target_os = "linux")]
+    pub const TABLE_MAIN: u32 = ifstructs::RT_TABLE_MAIN;
+    #[cfg(target_os = "linux")]
+    pub const TABLE_LOCAL: u32 = ifstructs::RT_TABLE_LOCAL;
+    /// Default FIB
+    #[cfg(target_os = "freebsd")]
+    pub const TABLE_MAIN: u32 = 0;
+
+    /// Unicast route in the main table
+    pub fn new(destination: IpNetwork) -> Route {
+        Route {
+            destination,
+            gateway: None,
+            iface: None,
+            nexthops: vec![],
+            source: None,
+            metric: None,
+            table: Route::TABLE_MAIN,
+            protocol: RouteProtocol::Boot,
+            scope: None,
+            kind: RouteType::Unicast,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn add(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::add_route(&mut nl, self)
+    }
+
+    /// Adds the route or replaces the existing one with the same destination
+    #[cfg(target_os = "linux")]
+    pub fn replace(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::replace_route(&mut nl, self)
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn delete(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::del_route(&mut nl, self)
+    }
+
+    /// Routes of all tables, both IPv4 and IPv6
+    #[cfg(target_os = "linux")]
+    pub fn list() -> Result<Vec<Route>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::list_routes(&mut nl)
+    }
+
+    /// Routes of the default FIB, both IPv4 and IPv6. Link-level entries are skipped
+    #[cfg(target_os = "freebsd")]
+    pub fn list() -> Result<Vec<Route>, IfError> {
+        let ctl_fd = impls::new_control_socket()?;
+        Ok(impls::list_routes(&ctl_fd)?
+            .iter()
+            .filter_map(impls::RouteRecord::to_route)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(target_os = "linux")]
+    use Iface;
+
+    #[test]
+    #[cfg(target_os = "freebsd")]
+    fn test_list_routes() {
+        let routes = Route::list().expect("list routes");
+        assert!(routes.iter().any(|r| r.destination.ip().is_loopback()
+            && r.iface.as_ref().map(|s| s.as_str()) == Some("lo0")));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_list_routes() {
+        let routes = Route::list().expect("list routes");
+        assert!(routes
+            .iter()
+            .any(|r| r.table == Route::TABLE_LOCAL && r.kind == RouteType::Local));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_add_replace_delete() {
+        let ctl_fd = impls::new_control_socket().unwrap();
+        impls::create_bridge(&ctl_fd, "ifctlroute0").expect("create bridge");
+        let mut iface = Iface::find_by_name("ifctlroute0").unwrap();
+        iface.up().unwrap();
+        iface
+            .add_addr("10.254.18.1/24".parse().unwrap())
+            .expect("add addr");
+
+        let mut route = Route::new("10.253.0.0/16".parse().unwrap());
+        route.gateway = Some("10.254.18.2".parse().unwrap());
+        route.iface = Some("ifctlroute0".to_owned());
+        route.add().expect("add route");
+        assert!(Route::list()
+            .unwrap()
+            .iter()
+            .any(|r| r.destination == route.destination && r.gateway == route.gateway));
+
+        route.gateway = Some("10.254.18.3".parse().unwrap());
+        route.replace().expect("replace route");
+        assert_eq!(
+            Route::list()
+                .unwrap()
+                .iter()
+                .filter(|r| r.destination == route.destination)
+                .map(|r| r.gateway)
+                .collect::<Vec<_>>(),
+            vec![route.gateway]
+        );
+
+        route.delete().expect("delete route");
+        assert!(!Route::list()
+            .unwrap()
+            .iter()
+            .any(|r| r.destination == route.destination));
+
+        let mut unreachable = Route::new("fd00:253::/48".parse().unwrap());
+        unreachable.kind = RouteType::Unreachable;
+        unreachable.table = 10253;
+        unreachable.add().expect("add unreachable route");
+        assert!(Route::list()
+            .unwrap()
+            .iter()
+            .any(|r| r.table == 10253 && r.kind == RouteType::Unreachable));
+        unreachable.delete().expect("delete unreachable route");
+
+        iface.down().unwrap();
+        impls::remove_bridge(&ctl_fd, "ifctlroute0").expect("remove bridge");
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_multipath() {
+        let ctl_fd = impls::new_control_socket().unwrap();
+        impls::create_bridge(&ctl_fd, "ifctlroute1").expect("create bridge");
+        let mut iface = Iface::find_by_name("ifctlroute1").unwrap();
+        iface.up().unwrap();
+        iface
+            .add_addr("10.254.19.1/24".parse().unwrap())
+            .expect("add addr");
+
+        let mut route = Route::new("10.252.0.0/16".parse().unwrap());
+        let mut first = NextHop::new("10.254.19.2".parse().unwrap());
+        first.iface = Some("ifctlroute1".to_owned());
+        let mut second = NextHop::new("10.254.19.3".parse().unwrap());
+        second.iface = Some("ifctlroute1".to_owned());
+        second.weight = 2;
+        route.nexthops = vec![first, second];
+        route.add().expect("add multipath route");
+
+        let listed = Route::list()
+            .unwrap()
+            .into_iter()
+            .find(|r| r.destination == route.destination)
+            .expect("multipath route");
+        assert_eq!(listed.gateway, None);
+        assert_eq!(listed.iface, None);
+        assert_eq!(listed.nexthops, route.nexthops);
+
+        route.nexthops[0].weight = 0;
+        assert!(route.replace().is_err());
+
+        listed.delete().expect("delete multipath route");
+        iface.down().unwrap();
+        impls::remove_bridge(&ctl_fd, "ifctlroute1").expect("remove bridge");
+    }
+}
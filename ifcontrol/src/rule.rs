@@ -0,0 +1,143 @@
+use ifstructs;
+use impls;
+use ipnetwork::IpNetwork;
+use {IfError, IpFamily};
+
+/// What to do with the packet, matched by the rule (`FR_ACT_*`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleAction {
+    /// Look up the route in the rule's table
+    Lookup,
+    Nop,
+    Blackhole,
+    Unreachable,
+    Prohibit,
+    Other(u8),
+}
+
+impl From<u8> for RuleAction {
+    fn from(v: u8) -> RuleAction {
+        match v {
+            ifstructs::FR_ACT_TO_TBL => RuleAction::Lookup,
+            ifstructs::FR_ACT_NOP => RuleAction::Nop,
+            ifstructs::FR_ACT_BLACKHOLE => RuleAction::Blackhole,
+            ifstructs::FR_ACT_UNREACHABLE => RuleAction::Unreachable,
+            ifstructs::FR_ACT_PROHIBIT => RuleAction::Prohibit,
+            v => RuleAction::Other(v),
+        }
+    }
+}
+
+impl From<RuleAction> for u8 {
+    fn from(a: RuleAction) -> u8 {
+        match a {
+            RuleAction::Lookup => ifstructs::FR_ACT_TO_TBL,
+            RuleAction::Nop => ifstructs::FR_ACT_NOP,
+            RuleAction::Blackhole => ifstructs::FR_ACT_BLACKHOLE,
+            RuleAction::Unreachable => ifstructs::FR_ACT_UNREACHABLE,
+            RuleAction::Prohibit => ifstructs::FR_ACT_PROHIBIT,
+            RuleAction::Other(v) => v,
+        }
+    }
+}
+
+/// Policy routing rule, the same as managed with `ip rule`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub family: IpFamily,
+    /// Kernel assigns the priority, if it's not set
+    pub priority: Option<u32>,
+    pub source: Option<IpNetwork>,
+    pub destination: Option<IpNetwork>,
+    /// Name of the incoming interface
+    pub iif: Option<String>,
+    /// Name of the outgoing interface
+    pub oif: Option<String>,
+    pub fwmark: Option<u32>,
+    /// All bits of the mark are matched by default. The full mask is listed as `None`
+    pub fwmask: Option<u32>,
+    pub table: u32,
+    /// Ignore routing decisions with the prefix length less or equal to this value
+    pub suppress_prefixlen: Option<u32>,
+    pub action: RuleAction,
+    /// Match packets, which don't satisfy the selector (`ip rule add not ...`)
+    pub invert: bool,
+}
+
+impl Rule {
+    /// Rule, which looks up the main table for all packets
+    pub fn new(family: IpFamily) -> Rule {
+        Rule {
+            family,
+            priority: None,
+            source: None,
+            destination: None,
+            iif: None,
+            oif: None,
+            fwmark: None,
+            fwmask: None,
+            table: ifstructs::RT_TABLE_MAIN,
+            suppress_prefixlen: None,
+            action: RuleAction::Lookup,
+            invert: false,
+        }
+    }
+
+    /// Rules of both IPv4 and IPv6 families
+    pub fn list() -> Result<Vec<Rule>, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::list_rules(&mut nl)
+    }
+
+    pub fn add(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::add_rule(&mut nl, self)
+    }
+
+    pub fn delete(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::del_rule(&mut nl, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_delete_rules() {
+        let mut fwmark = Rule::new(IpFamily::V4);
+        fwmark.priority = Some(31254);
+        fwmark.fwmark = Some(0x1254);
+        fwmark.fwmask = Some(0xffff);
+        fwmark.table = 10254;
+        fwmark.invert = true;
+        fwmark.add().expect("add fwmark rule");
+
+        let mut fwmark_only = Rule::new(IpFamily::V4);
+        fwmark_only.priority = Some(31256);
+        fwmark_only.fwmark = Some(0x1256);
+        fwmark_only.table = 10254;
+        fwmark_only.add().expect("add fwmark-only rule");
+
+        let mut suppress = Rule::new(IpFamily::V6);
+        suppress.priority = Some(31255);
+        suppress.suppress_prefixlen = Some(0);
+        suppress.source = Some("fd00:254::/64".parse().unwrap());
+        suppress.add().expect("add suppress rule");
+
+        let rules = Rule::list().expect("list rules");
+        assert!(rules.contains(&fwmark));
+        assert!(rules.contains(&fwmark_only));
+        assert!(rules.contains(&suppress));
+
+        fwmark.delete().expect("delete fwmark rule");
+        fwmark_only.delete().expect("delete fwmark-only rule");
+        suppress.delete().expect("delete suppress rule");
+
+        let rules = Rule::list().expect("list rules");
+        assert!(!rules.contains(&fwmark));
+        assert!(!rules.contains(&fwmark_only));
+        assert!(!rules.contains(&suppress));
+    }
+}
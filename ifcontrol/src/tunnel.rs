@@ -0,0 +1,124 @@
+use impls;
+use std::net::IpAddr;
+use {IfError, Iface};
+
+/// Type of the kernel L3 tunnel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TunnelKind {
+    /// IP over GRE over IPv4
+    Gre,
+    /// Ethernet over GRE over IPv4
+    GreTap,
+    /// IP over GRE over IPv6
+    Ip6Gre,
+    /// IPv4 over IPv4
+    Ipip,
+    /// IPv6 over IPv4
+    Sit,
+    /// IP over IPv6
+    Ip6Tnl,
+}
+
+/// Kernel tunnel interface (`ip link add <name> type <kind> local <local> remote <remote> ...`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tunnel {
+    pub name: String,
+    pub kind: TunnelKind,
+    pub local: Option<IpAddr>,
+    pub remote: Option<IpAddr>,
+    /// GRE key of the received packets
+    pub ikey: Option<u32>,
+    /// GRE key of the sent packets
+    pub okey: Option<u32>,
+    /// TTL of the outer packets, 0 inherits it from the inner ones
+    pub ttl: u8,
+    /// TOS of the outer IPv4 packets, 1 inherits it from the inner ones.
+    /// Honoured by the IPv4 kinds only, `Ip6Gre` and `Ip6Tnl` reject non-zero values
+    pub tos: u8,
+    /// Path MTU discovery over IPv4, enabled by default. Required for the non-zero `ttl`.
+    /// Honoured by the IPv4 kinds only, `Ip6Gre` and `Ip6Tnl` reject disabling it
+    pub pmtudisc: bool,
+}
+
+impl Tunnel {
+    pub fn new(name: &str, kind: TunnelKind) -> Tunnel {
+        Tunnel {
+            name: name.to_owned(),
+            kind,
+            local: None,
+            remote: None,
+            ikey: None,
+            okey: None,
+            ttl: 0,
+            tos: 0,
+            pmtudisc: true,
+        }
+    }
+
+    /// Settings of the existing tunnel. `IfError::NotFound` if it is not a tunnel
+    pub fn find_by_name(ifname: &str) -> Result<Tunnel, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::get_tunnel(&mut nl, ifname)
+    }
+
+    /// Creates the interface, which is down. It is removed by `Iface::delete`
+    pub fn create(&self) -> Result<Iface, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::create_tunnel(&mut nl, self)?;
+        Iface::find_by_name(&self.name)
+    }
+
+    /// Applies the settings to the existing tunnel with the same name and kind
+    pub fn update(&self) -> Result<(), IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::change_tunnel(&mut nl, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tests::skip_unsupported;
+
+    #[test]
+    fn test_create_update_delete() {
+        let mut gre = Tunnel::new("ifctlgre0", TunnelKind::Gre);
+        gre.local = Some("10.254.16.1".parse().unwrap());
+        gre.remote = Some("10.254.16.2".parse().unwrap());
+        gre.ikey = Some(16);
+        gre.okey = Some(16);
+        gre.ttl = 64;
+        let created = gre.create();
+        if skip_unsupported("gre", &created) {
+            return;
+        }
+        let iface = created.expect("create gre");
+        assert_eq!(Tunnel::find_by_name("ifctlgre0").unwrap(), gre);
+
+        gre.remote = Some("10.254.16.3".parse().unwrap());
+        gre.okey = Some(17);
+        gre.update().expect("update gre");
+        assert_eq!(Tunnel::find_by_name("ifctlgre0").unwrap(), gre);
+
+        iface.delete().expect("delete gre");
+    }
+
+    #[test]
+    fn test_ipip_sit() {
+        let kinds = [("ipip", TunnelKind::Ipip), ("sit", TunnelKind::Sit)];
+        for &(kind_name, kind) in &kinds {
+            let name = format!("ifctl{}0", kind_name);
+            let mut tunnel = Tunnel::new(&name, kind);
+            tunnel.local = Some("10.254.16.1".parse().unwrap());
+            tunnel.remote = Some("10.254.16.4".parse().unwrap());
+            tunnel.ttl = 64;
+            let created = tunnel.create();
+            if skip_unsupported(kind_name, &created) {
+                continue;
+            }
+            let iface = created.expect("create tunnel");
+            assert_eq!(Tunnel::find_by_name(&name).unwrap(), tunnel);
+            iface.delete().expect("delete tunnel");
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use eui48::MacAddress;
+use impls;
+use std::sync::Arc;
+use {IfError, Iface, NetNs};
+
+/// Pair of connected virtual ethernet interfaces (`ip link add ... type veth peer name ...`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Veth {
+    pub name: String,
+    pub peer_name: String,
+    /// Random address is generated by the kernel by default
+    pub hw_addr: Option<MacAddress>,
+    pub peer_hw_addr: Option<MacAddress>,
+    /// MTU of both ends
+    pub mtu: Option<u32>,
+    /// Number of RX and TX queues of both ends, the upper limit of `Iface::set_channels`
+    pub queues: Option<u32>,
+    /// Network namespace to put the peer into
+    pub peer_netns: Option<Arc<NetNs>>,
+}
+
+impl Veth {
+    pub fn new(name: &str, peer_name: &str) -> Veth {
+        Veth {
+            name: name.to_owned(),
+            peer_name: peer_name.to_owned(),
+            hw_addr: None,
+            peer_hw_addr: None,
+            mtu: None,
+            queues: None,
+            peer_netns: None,
+        }
+    }
+
+    /// Creates the pair and returns its first end. Both ends are down.
+    /// Deleting either end with `Iface::delete` removes the whole pair
+    pub fn create(&self) -> Result<Iface, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::create_veth(&mut nl, self)?;
+        Iface::find_by_name(&self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Link;
+
+    #[test]
+    fn test_create_delete() {
+        let mut veth = Veth::new("ifctlveth0", "ifctlveth1");
+        veth.hw_addr = Some(MacAddress::parse_str("02:00:00:00:12:01").unwrap());
+        veth.peer_hw_addr = Some(MacAddress::parse_str("02:00:00:00:12:02").unwrap());
+        veth.mtu = Some(1400);
+        let iface = veth.create().expect("create veth");
+
+        let peer = Iface::find_by_name("ifctlveth1").expect("find peer");
+        for (iface, hw_addr) in &[(&iface, veth.hw_addr), (&peer, veth.peer_hw_addr)] {
+            match iface.link() {
+                Link::Veth => {}
+                link => panic!("unexpected link type {:?}", link),
+            }
+            assert_eq!(iface.hw_addr(), *hw_addr);
+            assert_eq!(iface.mtu().unwrap(), 1400);
+        }
+        assert!(Iface::all()
+            .unwrap()
+            .iter()
+            .any(|i| i.name() == "ifctlveth0" && i.link() == &Link::Veth));
+
+        peer.delete().expect("delete veth peer");
+        match Iface::find_by_name("ifctlveth0") {
+            Err(IfError::NotFound) => {}
+            r => panic!("veth is not removed: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_peer_netns() {
+        let netns = Arc::new(NetNs::new().expect("create netns"));
+
+        let mut veth = Veth::new("ifctlveth2", "ifctlveth3");
+        veth.peer_netns = Some(netns.clone());
+        let iface = veth.create().expect("create veth");
+        match Iface::find_by_name("ifctlveth3") {
+            Err(IfError::NotFound) => {}
+            r => panic!("peer is not moved: {:?}", r),
+        }
+        let found = netns
+            .run(|| Iface::find_by_name("ifctlveth3").is_ok())
+            .unwrap();
+        assert!(found);
+
+        iface.delete().expect("delete veth");
+    }
+}
@@ -0,0 +1,92 @@
+pub use ifstructs::VlanFlags;
+use impls;
+use {IfError, Iface};
+
+/// Tag protocol of the VLAN interface
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VlanProtocol {
+    /// IEEE 802.1Q
+    Dot1Q,
+    /// IEEE 802.1ad (QinQ)
+    Dot1AD,
+}
+
+/// VLAN sub-interface (`ip link add link <parent> name <name> type vlan id <id>`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vlan {
+    pub name: String,
+    /// Name of the parent interface
+    pub parent: String,
+    pub id: u16,
+    pub protocol: VlanProtocol,
+    /// `VLAN_FLAG_REORDER_HDR` by default
+    pub flags: VlanFlags,
+    /// VLAN header priority to the packet priority, `(from, to)` pairs
+    pub ingress_qos_map: Vec<(u32, u32)>,
+    /// Packet priority to the VLAN header priority, `(from, to)` pairs
+    pub egress_qos_map: Vec<(u32, u32)>,
+}
+
+impl Vlan {
+    pub fn new(name: &str, parent: &str, id: u16) -> Vlan {
+        Vlan {
+            name: name.to_owned(),
+            parent: parent.to_owned(),
+            id,
+            protocol: VlanProtocol::Dot1Q,
+            flags: VlanFlags::VLAN_FLAG_REORDER_HDR,
+            ingress_qos_map: vec![],
+            egress_qos_map: vec![],
+        }
+    }
+
+    /// Settings of the existing VLAN interface. `IfError::NotFound` if it is not a VLAN
+    pub fn find_by_name(ifname: &str) -> Result<Vlan, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::get_vlan(&mut nl, ifname)
+    }
+
+    /// Creates the interface, which is down. It is removed by `Iface::delete`
+    pub fn create(&self) -> Result<Iface, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::create_vlan(&mut nl, self)?;
+        Iface::find_by_name(&self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tests::skip_unsupported;
+    use Link;
+    use Veth;
+
+    #[test]
+    fn test_create_delete() {
+        let parent = Veth::new("ifctlvlan0", "ifctlvlan1")
+            .create()
+            .expect("create veth");
+
+        let mut vlan = Vlan::new("ifctlvlan0.13", "ifctlvlan0", 13);
+        vlan.flags |= VlanFlags::VLAN_FLAG_GVRP;
+        vlan.ingress_qos_map = vec![(1, 2)];
+        vlan.egress_qos_map = vec![(3, 4)];
+        let created = vlan.create();
+        if skip_unsupported("vlan", &created) {
+            parent.delete().unwrap();
+            return;
+        }
+        let iface = created.expect("create vlan");
+        assert_eq!(
+            iface.link(),
+            &Link::Vlan {
+                parent: "ifctlvlan0".to_owned(),
+                id: 13,
+            }
+        );
+        assert_eq!(Vlan::find_by_name("ifctlvlan0.13").unwrap(), vlan);
+
+        iface.delete().expect("delete vlan");
+        parent.delete().expect("delete veth");
+    }
+}
@@ -0,0 +1,155 @@
+use impls;
+use std::net::IpAddr;
+use {IfError, Iface};
+
+/// VXLAN overlay interface (`ip link add <name> type vxlan id <vni> ...`).
+/// Remote endpoints besides the default one are added with `FdbEntry`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vxlan {
+    pub name: String,
+    /// VXLAN network identifier
+    pub vni: u32,
+    /// Source address of the outer packets
+    pub local: Option<IpAddr>,
+    /// Unicast destination of the frames with unknown addresses
+    pub remote: Option<IpAddr>,
+    /// Multicast destination of the frames with unknown addresses. Requires `dev`,
+    /// can't be set along with `remote`
+    pub group: Option<IpAddr>,
+    /// Destination UDP port, IANA-assigned 4789 by default
+    pub port: u16,
+    /// Underlay interface
+    pub dev: Option<String>,
+    /// TTL of the outer packets, 0 lets the kernel choose
+    pub ttl: u8,
+    /// Learn remote addresses from the received packets, enabled by default
+    pub learning: bool,
+    /// Checksum the outer UDP header, enabled by default
+    pub udp_csum: bool,
+}
+
+impl Vxlan {
+    pub fn new(name: &str, vni: u32) -> Vxlan {
+        Vxlan {
+            name: name.to_owned(),
+            vni,
+            local: None,
+            remote: None,
+            group: None,
+            port: 4789,
+            dev: None,
+            ttl: 0,
+            learning: true,
+            udp_csum: true,
+        }
+    }
+
+    /// Settings of the existing VXLAN interface. `IfError::NotFound` if it is not a VXLAN
+    pub fn find_by_name(ifname: &str) -> Result<Vxlan, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::get_vxlan(&mut nl, ifname)
+    }
+
+    /// Creates the interface, which is down. It is removed by `Iface::delete`
+    pub fn create(&self) -> Result<Iface, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::create_vxlan(&mut nl, self)?;
+        Iface::find_by_name(&self.name)
+    }
+}
+
+/// Geneve overlay interface (`ip link add <name> type geneve id <vni> remote <remote>`).
+/// Unlike VXLAN, it has a single remote endpoint and neither source address nor learning
+#[derive(Debug, Clone, PartialEq)]
+pub struct Geneve {
+    pub name: String,
+    /// Virtual network identifier
+    pub vni: u32,
+    pub remote: Option<IpAddr>,
+    /// Destination UDP port, IANA-assigned 6081 by default
+    pub port: u16,
+    /// TTL of the outer packets, 0 lets the kernel choose
+    pub ttl: u8,
+    /// Checksum the outer UDP header, enabled by default
+    pub udp_csum: bool,
+}
+
+impl Geneve {
+    pub fn new(name: &str, vni: u32) -> Geneve {
+        Geneve {
+            name: name.to_owned(),
+            vni,
+            remote: None,
+            port: 6081,
+            ttl: 0,
+            udp_csum: true,
+        }
+    }
+
+    /// Settings of the existing Geneve interface. `IfError::NotFound` if it is not a Geneve
+    pub fn find_by_name(ifname: &str) -> Result<Geneve, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::get_geneve(&mut nl, ifname)
+    }
+
+    /// Creates the interface, which is down. It is removed by `Iface::delete`
+    pub fn create(&self) -> Result<Iface, IfError> {
+        let mut nl = impls::NetlinkSocket::new()?;
+        impls::create_geneve(&mut nl, self)?;
+        Iface::find_by_name(&self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use tests::skip_unsupported;
+
+    #[test]
+    fn test_vxlan() {
+        let mut vxlan = Vxlan::new("ifctlvxlan0", 4215);
+        vxlan.local = Some("10.254.15.1".parse().unwrap());
+        vxlan.remote = Some("10.254.15.2".parse().unwrap());
+        vxlan.dev = Some("lo".to_owned());
+        vxlan.ttl = 5;
+        vxlan.learning = false;
+        vxlan.udp_csum = false;
+        let iface = vxlan.create().expect("create vxlan");
+        assert_eq!(Vxlan::find_by_name("ifctlvxlan0").unwrap(), vxlan);
+        iface.delete().expect("delete vxlan");
+
+        let mut vxlan = Vxlan::new("ifctlvxlan1", 4216);
+        vxlan.group = Some("ff05::4216".parse().unwrap());
+        vxlan.dev = Some("lo".to_owned());
+        vxlan.port = 8472;
+        let iface = vxlan.create().expect("create vxlan");
+        assert_eq!(Vxlan::find_by_name("ifctlvxlan1").unwrap(), vxlan);
+        iface.delete().expect("delete vxlan");
+
+        vxlan.remote = Some("10.254.15.2".parse().unwrap());
+        match vxlan.create() {
+            Err(IfError::Io(ref e)) if e.kind() == io::ErrorKind::InvalidInput => {}
+            r => panic!("both group and remote are accepted: {:?}", r),
+        }
+
+        match Vxlan::find_by_name("lo") {
+            Err(IfError::NotFound) => {}
+            r => panic!("lo is not a vxlan: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_geneve() {
+        let mut geneve = Geneve::new("ifctlgnv0", 4217);
+        geneve.remote = Some("10.254.15.2".parse().unwrap());
+        geneve.ttl = 5;
+        let created = geneve.create();
+        if skip_unsupported("geneve", &created) {
+            return;
+        }
+        let iface = created.expect("create geneve");
+        assert_eq!(Geneve::find_by_name("ifctlgnv0").unwrap(), geneve);
+        iface.delete().expect("delete geneve");
+    }
+}
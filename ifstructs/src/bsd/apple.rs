@@ -34,6 +34,24 @@ impl ::ifreq {
     pub unsafe fn set_addr(&mut self, addr: libc::sockaddr) {
         self.ifr_ifru.ifru_addr = addr;
     }
+
+    /// MTU, reported by `SIOCGIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Reads the union, so the request must be filled by the MTU ioctl
+    pub unsafe fn get_mtu(&self) -> libc::c_int {
+        self.ifr_ifru.ifru_mtu
+    }
+
+    /// MTU for `SIOCSIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Overwrites the union, so the request must only be passed to the MTU ioctl afterwards
+    pub unsafe fn set_mtu(&mut self, mtu: libc::c_int) {
+        self.ifr_ifru.ifru_mtu = mtu;
+    }
 }
 
 #[derive(Copy, Clone)]
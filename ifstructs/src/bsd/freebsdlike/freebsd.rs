@@ -372,4 +372,22 @@ impl ::ifreq {
     pub unsafe fn get_iface_index(&mut self) -> libc::c_short {
         self.ifr_ifru.ifru_index
     }
+
+    /// MTU, reported by `SIOCGIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Reads the union, so the request must be filled by the MTU ioctl
+    pub unsafe fn get_mtu(&self) -> libc::c_int {
+        self.ifr_ifru.ifru_mtu
+    }
+
+    /// MTU for `SIOCSIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Overwrites the union, so the request must only be passed to the MTU ioctl afterwards
+    pub unsafe fn set_mtu(&mut self, mtu: libc::c_int) {
+        self.ifr_ifru.ifru_mtu = mtu;
+    }
 }
\ No newline at end of file
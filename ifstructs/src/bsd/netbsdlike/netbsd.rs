@@ -32,3 +32,23 @@ pub struct ifaliasreq {
     pub ifra_broadaddr: libc::sockaddr,
     pub ifra_mask: libc::sockaddr,
 }
+
+impl ::ifreq {
+    /// MTU, reported by `SIOCGIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Reads the union, so the request must be filled by the MTU ioctl
+    pub unsafe fn get_mtu(&self) -> libc::c_int {
+        self.ifr_ifru.ifru_mtu
+    }
+
+    /// MTU for `SIOCSIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Overwrites the union, so the request must only be passed to the MTU ioctl afterwards
+    pub unsafe fn set_mtu(&mut self, mtu: libc::c_int) {
+        self.ifr_ifru.ifru_mtu = mtu;
+    }
+}
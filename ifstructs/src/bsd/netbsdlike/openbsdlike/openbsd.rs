@@ -13,3 +13,24 @@ pub union ifr_ifru {
     pub ifru_data: ::caddr_t,
     pub ifru_index: libc::c_uint,
 }
+
+impl ::ifreq {
+    // #define	ifr_mtu		ifr_ifru.ifru_metric	/* mtu (overload) */
+    /// MTU, reported by `SIOCGIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Reads the union, so the request must be filled by the MTU ioctl
+    pub unsafe fn get_mtu(&self) -> libc::c_int {
+        self.ifr_ifru.ifru_metric
+    }
+
+    /// MTU for `SIOCSIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Overwrites the union, so the request must only be passed to the MTU ioctl afterwards
+    pub unsafe fn set_mtu(&mut self, mtu: libc::c_int) {
+        self.ifr_ifru.ifru_metric = mtu;
+    }
+}
@@ -60,6 +60,11 @@ impl ::ifreq {
         unsafe { get_name!(self.ifrn_ifrn.ifrn_name) }
     }
 
+    /// Set new name for `SIOCSIFNAME`
+    pub fn set_new_name(&mut self, name: &str) -> io::Result<()> {
+        unsafe { set_name!(self.ifrn_ifru.ifrn_newname, name) }
+    }
+
     /// Get flags
     pub unsafe fn get_flags(&self) -> ::IfFlags {
         ::IfFlags::from_bits_truncate(i32::from(self.ifrn_ifru.ifru_flags))
@@ -79,5 +84,35 @@ impl ::ifreq {
     pub unsafe fn set_addr(&mut self, addr: libc::sockaddr) {
         self.ifrn_ifru.ifru_addr = addr;
     }
+
+    /// MTU, reported by `SIOCGIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Reads the union, so the request must be filled by the MTU ioctl
+    pub unsafe fn get_mtu(&self) -> libc::c_int {
+        self.ifrn_ifru.ifru_mtu
+    }
+
+    /// MTU for `SIOCSIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Overwrites the union, so the request must only be passed to the MTU ioctl afterwards
+    pub unsafe fn set_mtu(&mut self, mtu: libc::c_int) {
+        self.ifrn_ifru.ifru_mtu = mtu;
+    }
+
+    /// Set Ethernet hardware address
+    ///
+    /// # Safety
+    ///
+    /// Overwrites the union, so the request must only be passed to `SIOCSIFHWADDR` afterwards
+    pub unsafe fn set_hw_addr(&mut self, addr: [u8; 6]) {
+        self.ifrn_ifru.ifru_hwaddr.sa_family = libc::ARPHRD_ETHER;
+        for (dst, src) in self.ifrn_ifru.ifru_hwaddr.sa_data.iter_mut().zip(&addr) {
+            *dst = *src as libc::c_char;
+        }
+    }
 }
 
@@ -0,0 +1,306 @@
+use libc;
+use std::io;
+
+mod netlink;
+pub use self::netlink::*;
+
+#[repr(C)]
+pub union ifr_ifru {
+    pub ifr_addr: libc::sockaddr,
+    pub ifr_dstaddr: libc::sockaddr,
+    pub ifr_broadaddr: libc::sockaddr,
+    pub ifr_netmask: libc::sockaddr,
+    pub ifr_hwaddr: libc::sockaddr,
+    pub ifr_flags: libc::c_short,
+    pub ifr_ifindex: libc::c_int,
+    pub ifr_metric: libc::c_int,
+    pub ifr_mtu: libc::c_int,
+    pub ifr_map: ::ifmap,
+    pub ifr_slave: ::IfName,
+    pub ifr_newname: ::IfName,
+    pub ifr_data: *mut libc::c_char,
+}
+
+#[repr(C)]
+pub struct ifreq {
+    pub ifr_name: ::IfName,
+    pub ifr_ifru: ifr_ifru,
+}
+
+impl ::ifreq {
+    pub fn set_name(&mut self, name: &str) -> io::Result<()> {
+        set_name!(self.ifr_name, name)
+    }
+
+    pub fn get_name(&self) -> io::Result<String> {
+        get_name!(self.ifr_name)
+    }
+
+    /// Set new name for `SIOCSIFNAME`
+    pub fn set_new_name(&mut self, name: &str) -> io::Result<()> {
+        unsafe { set_name!(self.ifr_ifru.ifr_newname, name) }
+    }
+
+    /// Get flags
+    pub unsafe fn get_flags(&self) -> ::IfFlags {
+        ::IfFlags::from_bits_truncate(i32::from(self.ifr_ifru.ifr_flags))
+    }
+
+    /// Enable passed flags
+    pub unsafe fn set_flags(&mut self, flags: ::IfFlags) {
+        self.ifr_ifru.ifr_flags = flags.bits() as i16;
+    }
+
+    /// Enable passed flags
+    pub unsafe fn set_raw_flags(&mut self, raw_flags: libc::c_short) {
+        self.ifr_ifru.ifr_flags = raw_flags;
+    }
+
+    pub unsafe fn set_addr(&mut self, addr: libc::sockaddr) {
+        self.ifr_ifru.ifr_addr = addr;
+    }
+
+    pub unsafe fn set_iface_index(&mut self, idx: libc::c_int) {
+        self.ifr_ifru.ifr_ifindex = idx;
+    }
+
+    pub unsafe fn get_iface_index(&mut self) -> libc::c_int {
+        self.ifr_ifru.ifr_ifindex
+    }
+
+    /// MTU, reported by `SIOCGIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Reads the union, so the request must be filled by the MTU ioctl
+    pub unsafe fn get_mtu(&self) -> libc::c_int {
+        self.ifr_ifru.ifr_mtu
+    }
+
+    /// MTU for `SIOCSIFMTU`
+    ///
+    /// # Safety
+    ///
+    /// Overwrites the union, so the request must only be passed to the MTU ioctl afterwards
+    pub unsafe fn set_mtu(&mut self, mtu: libc::c_int) {
+        self.ifr_ifru.ifr_mtu = mtu;
+    }
+
+    /// Set Ethernet hardware address
+    ///
+    /// # Safety
+    ///
+    /// Overwrites the union, so the request must only be passed to `SIOCSIFHWADDR` afterwards
+    pub unsafe fn set_hw_addr(&mut self, addr: [u8; 6]) {
+        self.ifr_ifru.ifr_hwaddr.sa_family = libc::ARPHRD_ETHER;
+        for (dst, src) in self.ifr_ifru.ifr_hwaddr.sa_data.iter_mut().zip(&addr) {
+            *dst = *src as libc::c_char;
+        }
+    }
+}
+
+#[repr(C)]
+pub struct rtentry {
+    rt_pad1: libc::c_ulong,
+    rt_dst: libc::sockaddr,
+    rt_gateway: libc::sockaddr,
+    rt_genmask: libc::sockaddr,
+    rt_flags: libc::c_ushort,
+    rt_pad2: libc::c_short,
+    rt_pad3: libc::c_ulong,
+    rt_pad4: *const libc::c_void,
+    rt_metric: libc::c_short,
+    rt_dev: *mut libc::c_char,
+    rt_mtu: libc::c_ulong,
+    rt_window: libc::c_ulong,
+    rt_irtt: libc::c_ushort,
+}
+
+bitflags! {
+    pub struct RtFlags: libc::c_ushort {
+        const RTF_UP        = 0x0001;
+        const RTF_GATEWAY   = 0x0002;
+        const RTF_HOST      = 0x0004;
+        const RTF_REINSTATE = 0x0008;
+        const RTF_DYNAMIC   = 0x0010;
+        const RTF_MODIFIED  = 0x0020;
+        const RTF_MTU       = 0x0040; //RTF_MTU alias
+        const RTF_WINDOW    = 0x0080;
+        const RTF_IRTT      = 0x0100;
+        const RTF_REJECT    = 0x0200;
+    }
+}
+
+pub const ETHTOOL_FWVERS_LEN: usize = 32;
+pub const ETHTOOL_BUSINFO_LEN: usize = 32;
+pub const ETHTOOL_EROMVERS_LEN: usize = 32;
+
+#[repr(C)]
+pub struct ethtool_drvinfo {
+    pub cmd: u32,
+    pub driver: [u8; 32],
+    pub version: [u8; 32],
+    pub fw_version: [u8; ETHTOOL_FWVERS_LEN],
+    pub bus_info: [u8; ETHTOOL_BUSINFO_LEN],
+    pub erom_version: [u8; ETHTOOL_EROMVERS_LEN],
+    pub reserved2: [u8; 12],
+    pub n_priv_flags: u32,
+    pub n_stats: u32,
+    pub testinfo_len: u32,
+    pub eedump_len: u32,
+    pub regdump_len: u32,
+}
+
+pub const ETHTOOL_GDRVINFO: u32 = 0x00000003; /* Get driver info. */
+pub const ETHTOOL_GRINGPARAM: u32 = 0x00000010; /* Get ring parameters */
+pub const ETHTOOL_SRINGPARAM: u32 = 0x00000011; /* Set ring parameters. */
+pub const ETHTOOL_GSTRINGS: u32 = 0x0000001b; /* get specified string set */
+pub const ETHTOOL_GSTATS: u32 = 0x0000001d; /* get NIC-specific statistics */
+pub const ETHTOOL_GSSET_INFO: u32 = 0x00000037; /* Get string set info */
+pub const ETHTOOL_GFEATURES: u32 = 0x0000003a; /* Get device offload settings */
+pub const ETHTOOL_SFEATURES: u32 = 0x0000003b; /* Change device offload settings */
+pub const ETHTOOL_GCHANNELS: u32 = 0x0000003c; /* Get no of channels */
+pub const ETHTOOL_SCHANNELS: u32 = 0x0000003d; /* Set no of channels */
+pub const ETHTOOL_GLINKSETTINGS: u32 = 0x0000004c; /* Get ethtool_link_settings */
+pub const ETHTOOL_SLINKSETTINGS: u32 = 0x0000004d; /* Set ethtool_link_settings */
+
+pub const ETH_GSTRING_LEN: usize = 32;
+
+/* String sets */
+pub const ETH_SS_TEST: u32 = 0;
+pub const ETH_SS_STATS: u32 = 1;
+pub const ETH_SS_PRIV_FLAGS: u32 = 2;
+pub const ETH_SS_NTUPLE_FILTERS: u32 = 3;
+pub const ETH_SS_FEATURES: u32 = 4;
+
+/// Header of `ETHTOOL_GSTRINGS`, followed by `len` strings of `ETH_GSTRING_LEN` bytes
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ethtool_gstrings {
+    pub cmd: u32,
+    pub string_set: u32,
+    pub len: u32,
+}
+
+/// Header of `ETHTOOL_GSSET_INFO`, followed by u32 lengths of the sets present in `sset_mask`
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ethtool_sset_info {
+    pub cmd: u32,
+    pub reserved: u32,
+    pub sset_mask: u64,
+}
+
+/// Header of `ETHTOOL_GSTATS`, followed by `n_stats` u64 values
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ethtool_stats {
+    pub cmd: u32,
+    pub n_stats: u32,
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
+pub struct ethtool_get_features_block {
+    pub available: u32,     /* features togglable */
+    pub requested: u32,     /* features requested to be enabled */
+    pub active: u32,        /* features currently enabled */
+    pub never_changed: u32, /* features never changed by the user */
+}
+
+/// Header of `ETHTOOL_GFEATURES`, followed by `size` feature blocks
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ethtool_gfeatures {
+    pub cmd: u32,
+    pub size: u32,
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
+pub struct ethtool_set_features_block {
+    pub valid: u32,     /* bits valid in .requested */
+    pub requested: u32, /* features requested */
+}
+
+/// Header of `ETHTOOL_SFEATURES`, followed by `size` feature blocks
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ethtool_sfeatures {
+    pub cmd: u32,
+    pub size: u32,
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
+pub struct ethtool_ringparam {
+    pub cmd: u32,
+    pub rx_max_pending: u32,
+    pub rx_mini_max_pending: u32,
+    pub rx_jumbo_max_pending: u32,
+    pub tx_max_pending: u32,
+    pub rx_pending: u32,
+    pub rx_mini_pending: u32,
+    pub rx_jumbo_pending: u32,
+    pub tx_pending: u32,
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
+pub struct ethtool_channels {
+    pub cmd: u32,
+    pub max_rx: u32,
+    pub max_tx: u32,
+    pub max_other: u32,
+    pub max_combined: u32,
+    pub rx_count: u32,
+    pub tx_count: u32,
+    pub other_count: u32,
+    pub combined_count: u32,
+}
+
+/// Header of `ETHTOOL_GLINKSETTINGS`/`ETHTOOL_SLINKSETTINGS`, followed by supported, advertising
+/// and lp_advertising link mode masks of `link_mode_masks_nwords` u32 each
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
+pub struct ethtool_link_settings {
+    pub cmd: u32,
+    pub speed: u32,
+    pub duplex: u8,
+    pub port: u8,
+    pub phy_address: u8,
+    pub autoneg: u8,
+    pub mdio_support: u8,
+    pub eth_tp_mdix: u8,
+    pub eth_tp_mdix_ctrl: u8,
+    pub link_mode_masks_nwords: i8,
+    pub transceiver: u8,
+    pub master_slave_cfg: u8,
+    pub master_slave_state: u8,
+    pub rate_matching: u8,
+    pub reserved: [u32; 7],
+}
+
+pub const SPEED_UNKNOWN: u32 = 0xffffffff;
+
+pub const DUPLEX_HALF: u8 = 0x00;
+pub const DUPLEX_FULL: u8 = 0x01;
+pub const DUPLEX_UNKNOWN: u8 = 0xff;
+
+/* Which connector port. */
+pub const PORT_TP: u8 = 0x00;
+pub const PORT_AUI: u8 = 0x01;
+pub const PORT_BNC: u8 = 0x02;
+pub const PORT_MII: u8 = 0x03;
+pub const PORT_FIBRE: u8 = 0x04;
+pub const PORT_DA: u8 = 0x05;
+pub const PORT_NONE: u8 = 0xef;
+pub const PORT_OTHER: u8 = 0xff;
+
+pub const AUTONEG_DISABLE: u8 = 0x00;
+pub const AUTONEG_ENABLE: u8 = 0x01;
+
+/* ETHTOOL_SFEATURES return flags */
+pub const ETHTOOL_F_WISH: libc::c_int = 1 << 0; /* some features are requested but not enabled */
+pub const ETHTOOL_F_COMPAT: libc::c_int = 1 << 1; /* legacy ethtool call was used */
+pub const ETHTOOL_F_UNSUPPORTED: libc::c_int = 1 << 2; /* some features can't be changed */
@@ -0,0 +1,766 @@
+use libc;
+
+// include/uapi/linux/netlink.h
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct nlmsghdr {
+    pub nlmsg_len: u32,
+    pub nlmsg_type: u16,
+    pub nlmsg_flags: u16,
+    pub nlmsg_seq: u32,
+    pub nlmsg_pid: u32,
+}
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct nlmsgerr {
+    pub error: libc::c_int,
+    pub msg: nlmsghdr,
+}
+
+pub const NETLINK_ROUTE: libc::c_int = 0;
+
+pub const NLM_F_REQUEST: u16 = 0x01; /* It is request message. */
+pub const NLM_F_MULTI: u16 = 0x02; /* Multipart message, terminated by NLMSG_DONE */
+pub const NLM_F_ACK: u16 = 0x04; /* Reply with ack, with zero or error code */
+pub const NLM_F_ECHO: u16 = 0x08; /* Echo this request */
+
+/* Modifiers to GET request */
+pub const NLM_F_ROOT: u16 = 0x100; /* specify tree root */
+pub const NLM_F_MATCH: u16 = 0x200; /* return all matching */
+pub const NLM_F_ATOMIC: u16 = 0x400; /* atomic GET */
+pub const NLM_F_DUMP: u16 = NLM_F_ROOT | NLM_F_MATCH;
+
+/* Modifiers to NEW request */
+pub const NLM_F_REPLACE: u16 = 0x100; /* Override existing */
+pub const NLM_F_EXCL: u16 = 0x200; /* Do not touch, if it exists */
+pub const NLM_F_CREATE: u16 = 0x400; /* Create, if it does not exist */
+pub const NLM_F_APPEND: u16 = 0x800; /* Add to end of list */
+
+pub const NLMSG_NOOP: u16 = 0x1; /* Nothing. */
+pub const NLMSG_ERROR: u16 = 0x2; /* Error */
+pub const NLMSG_DONE: u16 = 0x3; /* End of a dump */
+pub const NLMSG_OVERRUN: u16 = 0x4; /* Data lost */
+
+pub const NLMSG_ALIGNTO: usize = 4;
+
+pub const fn nlmsg_align(len: usize) -> usize {
+    (len + NLMSG_ALIGNTO - 1) & !(NLMSG_ALIGNTO - 1)
+}
+
+pub const NLA_F_NESTED: u16 = 1 << 15;
+pub const NLA_F_NET_BYTEORDER: u16 = 1 << 14;
+pub const NLA_TYPE_MASK: u16 = !(NLA_F_NESTED | NLA_F_NET_BYTEORDER);
+
+// include/uapi/linux/rtnetlink.h
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct rtattr {
+    pub rta_len: u16,
+    pub rta_type: u16,
+}
+
+pub const RTA_ALIGNTO: usize = 4;
+
+pub const fn rta_align(len: usize) -> usize {
+    (len + RTA_ALIGNTO - 1) & !(RTA_ALIGNTO - 1)
+}
+
+pub const RTM_NEWLINK: u16 = 16;
+pub const RTM_DELLINK: u16 = 17;
+pub const RTM_GETLINK: u16 = 18;
+pub const RTM_SETLINK: u16 = 19;
+
+pub const RTM_NEWADDR: u16 = 20;
+pub const RTM_DELADDR: u16 = 21;
+pub const RTM_GETADDR: u16 = 22;
+
+pub const RTM_NEWROUTE: u16 = 24;
+pub const RTM_DELROUTE: u16 = 25;
+pub const RTM_GETROUTE: u16 = 26;
+
+pub const RTM_NEWNEIGH: u16 = 28;
+pub const RTM_DELNEIGH: u16 = 29;
+pub const RTM_GETNEIGH: u16 = 30;
+
+pub const RTM_NEWRULE: u16 = 32;
+pub const RTM_DELRULE: u16 = 33;
+pub const RTM_GETRULE: u16 = 34;
+
+pub const RTM_NEWMDB: u16 = 84;
+pub const RTM_DELMDB: u16 = 85;
+pub const RTM_GETMDB: u16 = 86;
+
+/* New extended info filters for IFLA_EXT_MASK */
+pub const RTEXT_FILTER_VF: u32 = 1 << 0;
+pub const RTEXT_FILTER_BRVLAN: u32 = 1 << 1;
+pub const RTEXT_FILTER_BRVLAN_COMPRESSED: u32 = 1 << 2;
+pub const RTEXT_FILTER_SKIP_STATS: u32 = 1 << 3;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ifinfomsg {
+    pub ifi_family: libc::c_uchar,
+    pub __ifi_pad: libc::c_uchar,
+    pub ifi_type: libc::c_ushort, /* ARPHRD_* */
+    pub ifi_index: libc::c_int,   /* Link index	*/
+    pub ifi_flags: libc::c_uint,  /* IFF_* flags	*/
+    pub ifi_change: libc::c_uint, /* IFF_* change mask */
+}
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct rtmsg {
+    pub rtm_family: libc::c_uchar,
+    pub rtm_dst_len: libc::c_uchar,
+    pub rtm_src_len: libc::c_uchar,
+    pub rtm_tos: libc::c_uchar,
+    pub rtm_table: libc::c_uchar,    /* Routing table id */
+    pub rtm_protocol: libc::c_uchar, /* Routing protocol; see below	*/
+    pub rtm_scope: libc::c_uchar,    /* See below */
+    pub rtm_type: libc::c_uchar,     /* See below	*/
+    pub rtm_flags: libc::c_uint,
+}
+
+pub const RTN_UNSPEC: u8 = 0;
+pub const RTN_UNICAST: u8 = 1; /* Gateway or direct route	*/
+pub const RTN_LOCAL: u8 = 2; /* Accept locally		*/
+pub const RTN_BROADCAST: u8 = 3; /* Accept locally as broadcast, send as broadcast */
+pub const RTN_ANYCAST: u8 = 4; /* Accept locally as broadcast, but send as unicast */
+pub const RTN_MULTICAST: u8 = 5; /* Multicast route		*/
+pub const RTN_BLACKHOLE: u8 = 6; /* Drop				*/
+pub const RTN_UNREACHABLE: u8 = 7; /* Destination is unreachable   */
+pub const RTN_PROHIBIT: u8 = 8; /* Administratively prohibited	*/
+pub const RTN_THROW: u8 = 9; /* Not in this table		*/
+pub const RTN_NAT: u8 = 10; /* Translate this address	*/
+pub const RTN_XRESOLVE: u8 = 11; /* Use external resolver	*/
+
+pub const RTPROT_UNSPEC: u8 = 0;
+pub const RTPROT_REDIRECT: u8 = 1; /* Route installed by ICMP redirects */
+pub const RTPROT_KERNEL: u8 = 2; /* Route installed by kernel		*/
+pub const RTPROT_BOOT: u8 = 3; /* Route installed during boot		*/
+pub const RTPROT_STATIC: u8 = 4; /* Route installed by administrator	*/
+pub const RTPROT_RA: u8 = 9; /* RDISC/ND router advertisements */
+pub const RTPROT_DHCP: u8 = 16; /* DHCP client */
+
+pub const RT_TABLE_UNSPEC: u32 = 0;
+/* User defined values */
+pub const RT_TABLE_COMPAT: u32 = 252;
+pub const RT_TABLE_DEFAULT: u32 = 253;
+pub const RT_TABLE_MAIN: u32 = 254;
+pub const RT_TABLE_LOCAL: u32 = 255;
+
+pub const RTM_F_NOTIFY: libc::c_uint = 0x100; /* Notify user of route change	*/
+pub const RTM_F_CLONED: libc::c_uint = 0x200; /* This route is cloned		*/
+
+pub const RTA_UNSPEC: u16 = 0;
+pub const RTA_DST: u16 = 1;
+pub const RTA_SRC: u16 = 2;
+pub const RTA_IIF: u16 = 3;
+pub const RTA_OIF: u16 = 4;
+pub const RTA_GATEWAY: u16 = 5;
+pub const RTA_PRIORITY: u16 = 6;
+pub const RTA_PREFSRC: u16 = 7;
+pub const RTA_METRICS: u16 = 8;
+pub const RTA_MULTIPATH: u16 = 9;
+pub const RTA_PROTOINFO: u16 = 10; /* no longer used */
+pub const RTA_FLOW: u16 = 11;
+pub const RTA_CACHEINFO: u16 = 12;
+pub const RTA_SESSION: u16 = 13; /* no longer used */
+pub const RTA_MP_ALGO: u16 = 14; /* no longer used */
+pub const RTA_TABLE: u16 = 15;
+pub const RTA_MARK: u16 = 16;
+
+/// Path of `RTA_MULTIPATH`, followed by its own attributes, e.g. `RTA_GATEWAY`
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct rtnexthop {
+    pub rtnh_len: libc::c_ushort,
+    pub rtnh_flags: libc::c_uchar,
+    pub rtnh_hops: libc::c_uchar, /* weight - 1 */
+    pub rtnh_ifindex: libc::c_int,
+}
+
+pub const RT_SCOPE_UNIVERSE: u8 = 0;
+/* User defined values  */
+pub const RT_SCOPE_SITE: u8 = 200;
+pub const RT_SCOPE_LINK: u8 = 253;
+pub const RT_SCOPE_HOST: u8 = 254;
+pub const RT_SCOPE_NOWHERE: u8 = 255;
+
+/* RTnetlink multicast groups - backwards compatibility for userspace */
+pub const RTMGRP_LINK: u32 = 1;
+pub const RTMGRP_NOTIFY: u32 = 2;
+pub const RTMGRP_NEIGH: u32 = 4;
+pub const RTMGRP_TC: u32 = 8;
+pub const RTMGRP_IPV4_IFADDR: u32 = 0x10;
+pub const RTMGRP_IPV4_MROUTE: u32 = 0x20;
+pub const RTMGRP_IPV4_ROUTE: u32 = 0x40;
+pub const RTMGRP_IPV4_RULE: u32 = 0x80;
+pub const RTMGRP_IPV6_IFADDR: u32 = 0x100;
+pub const RTMGRP_IPV6_MROUTE: u32 = 0x200;
+pub const RTMGRP_IPV6_ROUTE: u32 = 0x400;
+pub const RTMGRP_IPV6_IFINFO: u32 = 0x800;
+
+/* RTnetlink multicast groups */
+pub const RTNLGRP_NONE: u32 = 0;
+pub const RTNLGRP_LINK: u32 = 1;
+pub const RTNLGRP_NOTIFY: u32 = 2;
+pub const RTNLGRP_NEIGH: u32 = 3;
+pub const RTNLGRP_TC: u32 = 4;
+pub const RTNLGRP_IPV4_IFADDR: u32 = 5;
+pub const RTNLGRP_IPV4_MROUTE: u32 = 6;
+pub const RTNLGRP_IPV4_ROUTE: u32 = 7;
+pub const RTNLGRP_IPV4_RULE: u32 = 8;
+pub const RTNLGRP_IPV6_IFADDR: u32 = 9;
+pub const RTNLGRP_IPV6_MROUTE: u32 = 10;
+pub const RTNLGRP_IPV6_ROUTE: u32 = 11;
+pub const RTNLGRP_IPV6_IFINFO: u32 = 12;
+pub const RTNLGRP_IPV6_PREFIX: u32 = 18;
+pub const RTNLGRP_IPV6_RULE: u32 = 19;
+
+// include/uapi/linux/neighbour.h
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ndmsg {
+    pub ndm_family: u8,
+    pub ndm_pad1: u8,
+    pub ndm_pad2: u16,
+    pub ndm_ifindex: i32,
+    pub ndm_state: u16,
+    pub ndm_flags: u8,
+    pub ndm_type: u8,
+}
+
+pub const NDA_UNSPEC: u16 = 0;
+pub const NDA_DST: u16 = 1;
+pub const NDA_LLADDR: u16 = 2;
+pub const NDA_CACHEINFO: u16 = 3;
+pub const NDA_PROBES: u16 = 4;
+pub const NDA_VLAN: u16 = 5;
+pub const NDA_PORT: u16 = 6;
+pub const NDA_VNI: u16 = 7;
+pub const NDA_IFINDEX: u16 = 8;
+pub const NDA_MASTER: u16 = 9;
+
+bitflags! {
+    /// Neighbor cache entry flags
+    pub struct NtfFlags: u8 {
+        const NTF_USE         = 0x01;
+        const NTF_SELF        = 0x02;
+        const NTF_MASTER      = 0x04;
+        const NTF_PROXY       = 0x08; /* == ATF_PUBL */
+        const NTF_EXT_LEARNED = 0x10;
+        const NTF_OFFLOADED   = 0x20;
+        const NTF_ROUTER      = 0x80;
+    }
+}
+
+bitflags! {
+    /// Neighbor cache entry states
+    pub struct NudState: u16 {
+        const NUD_INCOMPLETE = 0x01;
+        const NUD_REACHABLE  = 0x02;
+        const NUD_STALE      = 0x04;
+        const NUD_DELAY      = 0x08;
+        const NUD_PROBE      = 0x10;
+        const NUD_FAILED     = 0x20;
+        /* Dummy states */
+        const NUD_NOARP      = 0x40;
+        const NUD_PERMANENT  = 0x80;
+    }
+}
+
+// include/uapi/linux/fib_rules.h
+
+/* rule is permanent, and cannot be deleted */
+pub const FIB_RULE_PERMANENT: u32 = 0x00000001;
+pub const FIB_RULE_INVERT: u32 = 0x00000002;
+pub const FIB_RULE_UNRESOLVED: u32 = 0x00000004;
+pub const FIB_RULE_IIF_DETACHED: u32 = 0x00000008;
+pub const FIB_RULE_OIF_DETACHED: u32 = 0x00000010;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct fib_rule_hdr {
+    pub family: u8,
+    pub dst_len: u8,
+    pub src_len: u8,
+    pub tos: u8,
+    pub table: u8,
+    pub res1: u8, /* reserved */
+    pub res2: u8, /* reserved */
+    pub action: u8,
+    pub flags: u32,
+}
+
+pub const FRA_UNSPEC: u16 = 0;
+pub const FRA_DST: u16 = 1; /* destination address */
+pub const FRA_SRC: u16 = 2; /* source address */
+pub const FRA_IIFNAME: u16 = 3; /* interface name */
+pub const FRA_GOTO: u16 = 4; /* target to jump to (FR_ACT_GOTO) */
+pub const FRA_PRIORITY: u16 = 6; /* priority/preference */
+pub const FRA_FWMARK: u16 = 10; /* mark */
+pub const FRA_FLOW: u16 = 11; /* flow/class id */
+pub const FRA_TUN_ID: u16 = 12;
+pub const FRA_SUPPRESS_IFGROUP: u16 = 13;
+pub const FRA_SUPPRESS_PREFIXLEN: u16 = 14;
+pub const FRA_TABLE: u16 = 15; /* Extended table id */
+pub const FRA_FWMASK: u16 = 16; /* mask for netfilter mark */
+pub const FRA_OIFNAME: u16 = 17;
+
+pub const FR_ACT_UNSPEC: u8 = 0;
+pub const FR_ACT_TO_TBL: u8 = 1; /* Pass to fixed table */
+pub const FR_ACT_GOTO: u8 = 2; /* Jump to another rule */
+pub const FR_ACT_NOP: u8 = 3; /* No operation */
+pub const FR_ACT_BLACKHOLE: u8 = 6; /* Drop without notification */
+pub const FR_ACT_UNREACHABLE: u8 = 7; /* Drop with ENETUNREACH */
+pub const FR_ACT_PROHIBIT: u8 = 8; /* Drop with EACCES */
+
+// include/uapi/linux/if.h
+
+/* RFC 2863 operational status */
+pub const IF_OPER_UNKNOWN: u8 = 0;
+pub const IF_OPER_NOTPRESENT: u8 = 1;
+pub const IF_OPER_DOWN: u8 = 2;
+pub const IF_OPER_LOWERLAYERDOWN: u8 = 3;
+pub const IF_OPER_TESTING: u8 = 4;
+pub const IF_OPER_DORMANT: u8 = 5;
+pub const IF_OPER_UP: u8 = 6;
+
+// include/uapi/linux/if_link.h
+
+pub const IFLA_UNSPEC: u16 = 0;
+pub const IFLA_ADDRESS: u16 = 1;
+pub const IFLA_BROADCAST: u16 = 2;
+pub const IFLA_IFNAME: u16 = 3;
+pub const IFLA_MTU: u16 = 4;
+pub const IFLA_LINK: u16 = 5;
+pub const IFLA_QDISC: u16 = 6;
+pub const IFLA_STATS: u16 = 7;
+pub const IFLA_COST: u16 = 8;
+pub const IFLA_PRIORITY: u16 = 9;
+pub const IFLA_MASTER: u16 = 10;
+pub const IFLA_WIRELESS: u16 = 11;
+pub const IFLA_PROTINFO: u16 = 12;
+pub const IFLA_TXQLEN: u16 = 13;
+pub const IFLA_MAP: u16 = 14;
+pub const IFLA_WEIGHT: u16 = 15;
+pub const IFLA_OPERSTATE: u16 = 16;
+pub const IFLA_LINKMODE: u16 = 17;
+pub const IFLA_LINKINFO: u16 = 18;
+pub const IFLA_NET_NS_PID: u16 = 19;
+pub const IFLA_IFALIAS: u16 = 20;
+pub const IFLA_NUM_VF: u16 = 21;
+pub const IFLA_VFINFO_LIST: u16 = 22;
+pub const IFLA_STATS64: u16 = 23;
+pub const IFLA_VF_PORTS: u16 = 24;
+pub const IFLA_PORT_SELF: u16 = 25;
+pub const IFLA_AF_SPEC: u16 = 26;
+pub const IFLA_GROUP: u16 = 27;
+pub const IFLA_NET_NS_FD: u16 = 28;
+pub const IFLA_EXT_MASK: u16 = 29;
+pub const IFLA_PROMISCUITY: u16 = 30;
+pub const IFLA_NUM_TX_QUEUES: u16 = 31;
+pub const IFLA_NUM_RX_QUEUES: u16 = 32;
+pub const IFLA_CARRIER: u16 = 33;
+pub const IFLA_PHYS_PORT_ID: u16 = 34;
+pub const IFLA_CARRIER_CHANGES: u16 = 35;
+pub const IFLA_PHYS_SWITCH_ID: u16 = 36;
+pub const IFLA_LINK_NETNSID: u16 = 37;
+
+/* The main device statistics structure */
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct rtnl_link_stats64 {
+    pub rx_packets: u64, /* total packets received	*/
+    pub tx_packets: u64, /* total packets transmitted	*/
+    pub rx_bytes: u64,   /* total bytes received 	*/
+    pub tx_bytes: u64,   /* total bytes transmitted	*/
+    pub rx_errors: u64,  /* bad packets received		*/
+    pub tx_errors: u64,  /* packet transmit problems	*/
+    pub rx_dropped: u64, /* no space in linux buffers	*/
+    pub tx_dropped: u64, /* no space available in linux	*/
+    pub multicast: u64,  /* multicast packets received	*/
+    pub collisions: u64,
+
+    /* detailed rx_errors: */
+    pub rx_length_errors: u64,
+    pub rx_over_errors: u64,   /* receiver ring buff overflow	*/
+    pub rx_crc_errors: u64,    /* recved pkt with crc error	*/
+    pub rx_frame_errors: u64,  /* recv'd frame alignment error */
+    pub rx_fifo_errors: u64,   /* recv'r fifo overrun		*/
+    pub rx_missed_errors: u64, /* receiver missed packet	*/
+
+    /* detailed tx_errors */
+    pub tx_aborted_errors: u64,
+    pub tx_carrier_errors: u64,
+    pub tx_fifo_errors: u64,
+    pub tx_heartbeat_errors: u64,
+    pub tx_window_errors: u64,
+
+    /* for cslip etc */
+    pub rx_compressed: u64,
+    pub tx_compressed: u64,
+
+    pub rx_nohandler: u64, /* dropped, no handler found	*/
+}
+
+pub const IFLA_INFO_UNSPEC: u16 = 0;
+pub const IFLA_INFO_KIND: u16 = 1;
+pub const IFLA_INFO_DATA: u16 = 2;
+pub const IFLA_INFO_XSTATS: u16 = 3;
+pub const IFLA_INFO_SLAVE_KIND: u16 = 4;
+pub const IFLA_INFO_SLAVE_DATA: u16 = 5;
+
+pub const IFLA_BR_UNSPEC: u16 = 0;
+pub const IFLA_BR_FORWARD_DELAY: u16 = 1;
+pub const IFLA_BR_HELLO_TIME: u16 = 2;
+pub const IFLA_BR_MAX_AGE: u16 = 3;
+pub const IFLA_BR_AGEING_TIME: u16 = 4;
+pub const IFLA_BR_STP_STATE: u16 = 5;
+pub const IFLA_BR_PRIORITY: u16 = 6;
+pub const IFLA_BR_VLAN_FILTERING: u16 = 7;
+pub const IFLA_BR_VLAN_PROTOCOL: u16 = 8;
+pub const IFLA_BR_GROUP_FWD_MASK: u16 = 9;
+pub const IFLA_BR_ROOT_ID: u16 = 10;
+pub const IFLA_BR_BRIDGE_ID: u16 = 11;
+pub const IFLA_BR_ROOT_PORT: u16 = 12;
+pub const IFLA_BR_ROOT_PATH_COST: u16 = 13;
+pub const IFLA_BR_TOPOLOGY_CHANGE: u16 = 14;
+pub const IFLA_BR_TOPOLOGY_CHANGE_DETECTED: u16 = 15;
+pub const IFLA_BR_HELLO_TIMER: u16 = 16;
+pub const IFLA_BR_TCN_TIMER: u16 = 17;
+pub const IFLA_BR_TOPOLOGY_CHANGE_TIMER: u16 = 18;
+pub const IFLA_BR_GC_TIMER: u16 = 19;
+pub const IFLA_BR_GROUP_ADDR: u16 = 20;
+pub const IFLA_BR_FDB_FLUSH: u16 = 21;
+pub const IFLA_BR_MCAST_ROUTER: u16 = 22;
+pub const IFLA_BR_MCAST_SNOOPING: u16 = 23;
+
+pub const IFLA_BOND_UNSPEC: u16 = 0;
+pub const IFLA_BOND_MODE: u16 = 1;
+pub const IFLA_BOND_ACTIVE_SLAVE: u16 = 2;
+pub const IFLA_BOND_MIIMON: u16 = 3;
+pub const IFLA_BOND_UPDELAY: u16 = 4;
+pub const IFLA_BOND_DOWNDELAY: u16 = 5;
+pub const IFLA_BOND_USE_CARRIER: u16 = 6;
+pub const IFLA_BOND_ARP_INTERVAL: u16 = 7;
+pub const IFLA_BOND_ARP_IP_TARGET: u16 = 8;
+pub const IFLA_BOND_ARP_VALIDATE: u16 = 9;
+pub const IFLA_BOND_ARP_ALL_TARGETS: u16 = 10;
+pub const IFLA_BOND_PRIMARY: u16 = 11;
+pub const IFLA_BOND_PRIMARY_RESELECT: u16 = 12;
+pub const IFLA_BOND_FAIL_OVER_MAC: u16 = 13;
+pub const IFLA_BOND_XMIT_HASH_POLICY: u16 = 14;
+pub const IFLA_BOND_RESEND_IGMP: u16 = 15;
+pub const IFLA_BOND_NUM_PEER_NOTIF: u16 = 16;
+pub const IFLA_BOND_ALL_SLAVES_ACTIVE: u16 = 17;
+pub const IFLA_BOND_MIN_LINKS: u16 = 18;
+pub const IFLA_BOND_LP_INTERVAL: u16 = 19;
+pub const IFLA_BOND_PACKETS_PER_SLAVE: u16 = 20;
+pub const IFLA_BOND_AD_LACP_RATE: u16 = 21;
+pub const IFLA_BOND_AD_SELECT: u16 = 22;
+pub const IFLA_BOND_AD_INFO: u16 = 23;
+
+pub const IFLA_BOND_SLAVE_UNSPEC: u16 = 0;
+pub const IFLA_BOND_SLAVE_STATE: u16 = 1;
+pub const IFLA_BOND_SLAVE_MII_STATUS: u16 = 2;
+pub const IFLA_BOND_SLAVE_LINK_FAILURE_COUNT: u16 = 3;
+pub const IFLA_BOND_SLAVE_PERM_HWADDR: u16 = 4;
+pub const IFLA_BOND_SLAVE_QUEUE_ID: u16 = 5;
+pub const IFLA_BOND_SLAVE_AD_AGGREGATOR_ID: u16 = 6;
+
+pub const IFLA_VLAN_UNSPEC: u16 = 0;
+pub const IFLA_VLAN_ID: u16 = 1;
+pub const IFLA_VLAN_FLAGS: u16 = 2;
+pub const IFLA_VLAN_EGRESS_QOS: u16 = 3;
+pub const IFLA_VLAN_INGRESS_QOS: u16 = 4;
+pub const IFLA_VLAN_PROTOCOL: u16 = 5;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ifla_vlan_flags {
+    pub flags: u32,
+    pub mask: u32,
+}
+
+pub const IFLA_VLAN_QOS_UNSPEC: u16 = 0;
+pub const IFLA_VLAN_QOS_MAPPING: u16 = 1;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ifla_vlan_qos_mapping {
+    pub from: u32,
+    pub to: u32,
+}
+
+pub const IFLA_MACVLAN_UNSPEC: u16 = 0;
+pub const IFLA_MACVLAN_MODE: u16 = 1;
+pub const IFLA_MACVLAN_FLAGS: u16 = 2;
+
+pub const MACVLAN_MODE_PRIVATE: u32 = 1; /* don't talk to other macvlans */
+pub const MACVLAN_MODE_VEPA: u32 = 2; /* talk to other ports through ext bridge */
+pub const MACVLAN_MODE_BRIDGE: u32 = 4; /* talk to bridge ports directly */
+pub const MACVLAN_MODE_PASSTHRU: u32 = 8; /* take over the underlying device */
+pub const MACVLAN_MODE_SOURCE: u32 = 16; /* use source MAC address list to assign */
+
+pub const IFLA_IPVLAN_UNSPEC: u16 = 0;
+pub const IFLA_IPVLAN_MODE: u16 = 1;
+pub const IFLA_IPVLAN_FLAGS: u16 = 2;
+
+pub const IPVLAN_MODE_L2: u16 = 0;
+pub const IPVLAN_MODE_L3: u16 = 1;
+pub const IPVLAN_MODE_L3S: u16 = 2;
+
+pub const IFLA_VXLAN_UNSPEC: u16 = 0;
+pub const IFLA_VXLAN_ID: u16 = 1;
+pub const IFLA_VXLAN_GROUP: u16 = 2;
+pub const IFLA_VXLAN_LINK: u16 = 3;
+pub const IFLA_VXLAN_LOCAL: u16 = 4;
+pub const IFLA_VXLAN_TTL: u16 = 5;
+pub const IFLA_VXLAN_TOS: u16 = 6;
+pub const IFLA_VXLAN_LEARNING: u16 = 7;
+pub const IFLA_VXLAN_AGEING: u16 = 8;
+pub const IFLA_VXLAN_LIMIT: u16 = 9;
+pub const IFLA_VXLAN_PORT_RANGE: u16 = 10;
+pub const IFLA_VXLAN_PROXY: u16 = 11;
+pub const IFLA_VXLAN_RSC: u16 = 12;
+pub const IFLA_VXLAN_L2MISS: u16 = 13;
+pub const IFLA_VXLAN_L3MISS: u16 = 14;
+pub const IFLA_VXLAN_PORT: u16 = 15;
+pub const IFLA_VXLAN_GROUP6: u16 = 16;
+pub const IFLA_VXLAN_LOCAL6: u16 = 17;
+pub const IFLA_VXLAN_UDP_CSUM: u16 = 18;
+pub const IFLA_VXLAN_UDP_ZERO_CSUM6_TX: u16 = 19;
+pub const IFLA_VXLAN_UDP_ZERO_CSUM6_RX: u16 = 20;
+pub const IFLA_VXLAN_REMCSUM_TX: u16 = 21;
+pub const IFLA_VXLAN_REMCSUM_RX: u16 = 22;
+pub const IFLA_VXLAN_GBP: u16 = 23;
+pub const IFLA_VXLAN_REMCSUM_NOPARTIAL: u16 = 24;
+pub const IFLA_VXLAN_COLLECT_METADATA: u16 = 25;
+pub const IFLA_VXLAN_LABEL: u16 = 26;
+pub const IFLA_VXLAN_GPE: u16 = 27;
+pub const IFLA_VXLAN_TTL_INHERIT: u16 = 28;
+pub const IFLA_VXLAN_DF: u16 = 29;
+
+pub const IFLA_GENEVE_UNSPEC: u16 = 0;
+pub const IFLA_GENEVE_ID: u16 = 1;
+pub const IFLA_GENEVE_REMOTE: u16 = 2;
+pub const IFLA_GENEVE_TTL: u16 = 3;
+pub const IFLA_GENEVE_TOS: u16 = 4;
+pub const IFLA_GENEVE_PORT: u16 = 5;
+pub const IFLA_GENEVE_COLLECT_METADATA: u16 = 6;
+pub const IFLA_GENEVE_REMOTE6: u16 = 7;
+pub const IFLA_GENEVE_UDP_CSUM: u16 = 8;
+pub const IFLA_GENEVE_UDP_ZERO_CSUM6_TX: u16 = 9;
+pub const IFLA_GENEVE_UDP_ZERO_CSUM6_RX: u16 = 10;
+pub const IFLA_GENEVE_LABEL: u16 = 11;
+pub const IFLA_GENEVE_TTL_INHERIT: u16 = 12;
+pub const IFLA_GENEVE_DF: u16 = 13;
+
+// include/uapi/linux/if_tunnel.h
+
+pub const IFLA_IPTUN_UNSPEC: u16 = 0;
+pub const IFLA_IPTUN_LINK: u16 = 1;
+pub const IFLA_IPTUN_LOCAL: u16 = 2;
+pub const IFLA_IPTUN_REMOTE: u16 = 3;
+pub const IFLA_IPTUN_TTL: u16 = 4;
+pub const IFLA_IPTUN_TOS: u16 = 5;
+pub const IFLA_IPTUN_ENCAP_LIMIT: u16 = 6;
+pub const IFLA_IPTUN_FLOWINFO: u16 = 7;
+pub const IFLA_IPTUN_FLAGS: u16 = 8;
+pub const IFLA_IPTUN_PROTO: u16 = 9;
+pub const IFLA_IPTUN_PMTUDISC: u16 = 10;
+
+/* GRE flags, sent in IFLA_GRE_IFLAGS and IFLA_GRE_OFLAGS as __be16 */
+pub const GRE_CSUM: u16 = 0x8000;
+pub const GRE_ROUTING: u16 = 0x4000;
+pub const GRE_KEY: u16 = 0x2000;
+pub const GRE_SEQ: u16 = 0x1000;
+
+pub const IFLA_GRE_UNSPEC: u16 = 0;
+pub const IFLA_GRE_LINK: u16 = 1;
+pub const IFLA_GRE_IFLAGS: u16 = 2;
+pub const IFLA_GRE_OFLAGS: u16 = 3;
+pub const IFLA_GRE_IKEY: u16 = 4;
+pub const IFLA_GRE_OKEY: u16 = 5;
+pub const IFLA_GRE_LOCAL: u16 = 6;
+pub const IFLA_GRE_REMOTE: u16 = 7;
+pub const IFLA_GRE_TTL: u16 = 8;
+pub const IFLA_GRE_TOS: u16 = 9;
+pub const IFLA_GRE_PMTUDISC: u16 = 10;
+pub const IFLA_GRE_ENCAP_LIMIT: u16 = 11;
+pub const IFLA_GRE_FLOWINFO: u16 = 12;
+pub const IFLA_GRE_FLAGS: u16 = 13;
+
+// include/uapi/linux/if_vlan.h
+
+bitflags! {
+    /// Flags of the VLAN interface
+    pub struct VlanFlags: u32 {
+        const VLAN_FLAG_REORDER_HDR = 0x1;
+        const VLAN_FLAG_GVRP = 0x2;
+        const VLAN_FLAG_LOOSE_BINDING = 0x4;
+        const VLAN_FLAG_MVRP = 0x8;
+        const VLAN_FLAG_BRIDGE_BINDING = 0x10;
+    }
+}
+
+// include/uapi/linux/if_bridge.h
+
+pub const BRIDGE_FLAGS_MASTER: u16 = 1; /* Bridge command to/from master */
+pub const BRIDGE_FLAGS_SELF: u16 = 2; /* Bridge command to/from lowerdev */
+
+pub const IFLA_BRIDGE_FLAGS: u16 = 0;
+pub const IFLA_BRIDGE_MODE: u16 = 1;
+pub const IFLA_BRIDGE_VLAN_INFO: u16 = 2;
+
+pub const BRIDGE_VLAN_INFO_MASTER: u16 = 1 << 0; /* Operate on Bridge device as well */
+pub const BRIDGE_VLAN_INFO_PVID: u16 = 1 << 1; /* VLAN is PVID, ingress untagged */
+pub const BRIDGE_VLAN_INFO_UNTAGGED: u16 = 1 << 2; /* VLAN egresses untagged */
+pub const BRIDGE_VLAN_INFO_RANGE_BEGIN: u16 = 1 << 3; /* VLAN is start of vlan range */
+pub const BRIDGE_VLAN_INFO_RANGE_END: u16 = 1 << 4; /* VLAN is end of vlan range */
+pub const BRIDGE_VLAN_INFO_BRENTRY: u16 = 1 << 5; /* Global bridge VLAN entry */
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct bridge_vlan_info {
+    pub flags: u16,
+    pub vid: u16,
+}
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct br_port_msg {
+    pub family: u8,
+    pub ifindex: u32,
+}
+
+pub const MDBA_UNSPEC: u16 = 0;
+pub const MDBA_MDB: u16 = 1;
+pub const MDBA_ROUTER: u16 = 2;
+
+pub const MDBA_MDB_UNSPEC: u16 = 0;
+pub const MDBA_MDB_ENTRY: u16 = 1;
+
+pub const MDBA_MDB_ENTRY_UNSPEC: u16 = 0;
+pub const MDBA_MDB_ENTRY_INFO: u16 = 1;
+
+pub const MDBA_SET_ENTRY_UNSPEC: u16 = 0;
+pub const MDBA_SET_ENTRY: u16 = 1;
+
+pub const MDB_TEMPORARY: u8 = 0;
+pub const MDB_PERMANENT: u8 = 1;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub union br_mdb_entry_addr_u {
+    pub ip4: u32,
+    pub ip6: libc::in6_addr,
+    pub mac_addr: [u8; 6],
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct br_mdb_entry_addr {
+    pub u: br_mdb_entry_addr_u,
+    pub proto: u16,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct br_mdb_entry {
+    pub ifindex: u32,
+    pub state: u8,
+    pub flags: u8,
+    pub vid: u16,
+    pub addr: br_mdb_entry_addr,
+}
+
+// include/uapi/linux/if_bonding.h
+
+pub const BOND_MODE_ROUNDROBIN: u8 = 0;
+pub const BOND_MODE_ACTIVEBACKUP: u8 = 1;
+pub const BOND_MODE_XOR: u8 = 2;
+pub const BOND_MODE_BROADCAST: u8 = 3;
+pub const BOND_MODE_8023AD: u8 = 4;
+pub const BOND_MODE_TLB: u8 = 5;
+pub const BOND_MODE_ALB: u8 = 6; /* TLB + RLB (receive load balancing) */
+
+/* each slave's link has 4 states */
+pub const BOND_LINK_UP: u8 = 0; /* link is up and running */
+pub const BOND_LINK_FAIL: u8 = 1; /* link has just gone down */
+pub const BOND_LINK_DOWN: u8 = 2; /* link has been down for too long time */
+pub const BOND_LINK_BACK: u8 = 3; /* link is going back */
+
+/* each slave has several states */
+pub const BOND_STATE_ACTIVE: u8 = 0; /* link is active */
+pub const BOND_STATE_BACKUP: u8 = 1; /* link is backup */
+
+/* hashing types */
+pub const BOND_XMIT_POLICY_LAYER2: u8 = 0; /* layer 2 (MAC only), default */
+pub const BOND_XMIT_POLICY_LAYER34: u8 = 1; /* layer 3+4 (IP ^ (TCP || UDP)) */
+pub const BOND_XMIT_POLICY_LAYER23: u8 = 2; /* layer 2+3 (IP ^ MAC) */
+pub const BOND_XMIT_POLICY_ENCAP23: u8 = 3; /* encapsulated layer 2+3 */
+pub const BOND_XMIT_POLICY_ENCAP34: u8 = 4; /* encapsulated layer 3+4 */
+
+// include/uapi/linux/veth.h
+
+pub const VETH_INFO_UNSPEC: u16 = 0;
+pub const VETH_INFO_PEER: u16 = 1;
+
+// include/uapi/linux/if_addr.h
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ifaddrmsg {
+    pub ifa_family: u8,
+    pub ifa_prefixlen: u8, /* The prefix length		*/
+    pub ifa_flags: u8,     /* Flags			*/
+    pub ifa_scope: u8,     /* Address scope		*/
+    pub ifa_index: u32,    /* Link index			*/
+}
+
+pub const IFA_UNSPEC: u16 = 0;
+pub const IFA_ADDRESS: u16 = 1;
+pub const IFA_LOCAL: u16 = 2;
+pub const IFA_LABEL: u16 = 3;
+pub const IFA_BROADCAST: u16 = 4;
+pub const IFA_ANYCAST: u16 = 5;
+pub const IFA_CACHEINFO: u16 = 6;
+pub const IFA_MULTICAST: u16 = 7;
+pub const IFA_FLAGS: u16 = 8;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ifa_cacheinfo {
+    pub ifa_prefered: u32,
+    pub ifa_valid: u32,
+    pub cstamp: u32, /* created timestamp, hundredths of seconds */
+    pub tstamp: u32, /* updated timestamp, hundredths of seconds */
+}
+
+pub const INFINITY_LIFE_TIME: u32 = 0xFFFFFFFF;
+
+bitflags! {
+    /// Address flags, the first 8 of them are also in `ifa_flags`
+    pub struct IfaFlags: u32 {
+        const IFA_F_SECONDARY      = 0x01;
+        const IFA_F_TEMPORARY      = 0x01;
+        const IFA_F_NODAD          = 0x02;
+        const IFA_F_OPTIMISTIC     = 0x04;
+        const IFA_F_DADFAILED      = 0x08;
+        const IFA_F_HOMEADDRESS    = 0x10;
+        const IFA_F_DEPRECATED     = 0x20;
+        const IFA_F_TENTATIVE      = 0x40;
+        const IFA_F_PERMANENT      = 0x80;
+        const IFA_F_MANAGETEMPADDR = 0x100;
+        const IFA_F_NOPREFIXROUTE  = 0x200;
+        const IFA_F_MCAUTOJOIN     = 0x400;
+        const IFA_F_STABLE_PRIVACY = 0x800;
+    }
+}
@@ -16,11 +16,13 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use TunTapError;
 
-pub struct Native {}
+pub struct Native {
+    mtu: Option<u32>,
+}
 
 impl Default for Native {
     fn default() -> Native {
-        Native {}
+        Native { mtu: None }
     }
 }
 
@@ -29,6 +31,12 @@ impl Native {
         Native::default()
     }
 
+    /// Sets MTU of the created interfaces before bringing them up
+    pub fn with_mtu(mut self, mtu: u32) -> Native {
+        self.mtu = Some(mtu);
+        self
+    }
+
     pub fn create_tun(&self) -> Result<::Virtualnterface<::Descriptor<Native>>, TunTapError> {
         let (file, name) = self.create(::VirtualInterfaceType::Tun, false)?;
         let info = Arc::new(Mutex::new(::VirtualInterfaceInfo {
@@ -161,7 +169,11 @@ impl Native {
             }
         }
 
-        Iface::find_by_name(&name)?.up()?;
+        let mut iface = Iface::find_by_name(&name)?;
+        if let Some(mtu) = self.mtu {
+            iface.set_mtu(mtu)?;
+        }
+        iface.up()?;
 
         Ok((file, name))
     }
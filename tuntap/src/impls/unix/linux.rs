@@ -1,16 +1,50 @@
 use crate::evented::EventedDescriptor;
 use crate::poll_evented::PollEvented;
+use eui48::MacAddress;
+#[cfg(target_os = "linux")]
+use ifcontrol::NetNs;
 use parking_lot::Mutex;
 use std::fs::File;
 use std::os::unix::prelude::*;
 use std::sync::Arc;
 use TunTapError;
 
-pub struct Native {}
+pub struct Native {
+    mtu: Option<u32>,
+    hw_addr: Option<MacAddress>,
+    #[cfg(target_os = "linux")]
+    netns: Option<Arc<NetNs>>,
+}
 
 impl Native {
     pub fn new() -> Native {
-        Native {}
+        Native {
+            mtu: None,
+            hw_addr: None,
+            #[cfg(target_os = "linux")]
+            netns: None,
+        }
+    }
+
+    /// Sets MTU of the created interfaces before bringing them up
+    pub fn with_mtu(mut self, mtu: u32) -> Native {
+        self.mtu = Some(mtu);
+        self
+    }
+
+    /// Sets hardware address of the created tap interfaces before bringing them up
+    pub fn with_hw_addr(mut self, hw_addr: MacAddress) -> Native {
+        self.hw_addr = Some(hw_addr);
+        self
+    }
+
+    /// Creates the interfaces inside the network namespace. The calling thread is switched
+    /// into it only for the time of creation and of later operations, like `rename`, while
+    /// the returned queues keep working from any namespace
+    #[cfg(target_os = "linux")]
+    pub fn with_netns(mut self, netns: NetNs) -> Native {
+        self.netns = Some(Arc::new(netns));
+        self
     }
 
     pub unsafe fn tun_async_from_fds(
@@ -21,6 +55,8 @@ impl Native {
         let info = Arc::new(Mutex::new(::VirtualInterfaceInfo {
             name: ifname.to_owned(),
             iface_type: ::VirtualInterfaceType::Tun,
+            #[cfg(target_os = "linux")]
+            netns: self.netns.clone(),
         }));
         Ok(::Virtualnterface {
             queues: fds
@@ -41,6 +77,8 @@ impl Native {
         let info = Arc::new(Mutex::new(::VirtualInterfaceInfo {
             name: ifname.to_owned(),
             iface_type: ::VirtualInterfaceType::Tun,
+            #[cfg(target_os = "linux")]
+            netns: self.netns.clone(),
         }));
         Ok(::Virtualnterface {
             queues: fds
@@ -104,6 +142,7 @@ mod normal_linux {
             let info = Arc::new(Mutex::new(::VirtualInterfaceInfo {
                 name,
                 iface_type: ::VirtualInterfaceType::Tun,
+                netns: self.netns.clone(),
             }));
 
             Ok(::Virtualnterface {
@@ -124,6 +163,7 @@ mod normal_linux {
             let info = Arc::new(Mutex::new(::VirtualInterfaceInfo {
                 name,
                 iface_type: ::VirtualInterfaceType::Tap,
+                netns: self.netns.clone(),
             }));
             Ok(::Virtualnterface {
                 queues: files
@@ -144,6 +184,7 @@ mod normal_linux {
             let info = Arc::new(Mutex::new(::VirtualInterfaceInfo {
                 name,
                 iface_type: ::VirtualInterfaceType::Tun,
+                netns: self.netns.clone(),
             }));
             Ok(::Virtualnterface {
                 queues: files
@@ -164,6 +205,7 @@ mod normal_linux {
             let info = Arc::new(Mutex::new(::VirtualInterfaceInfo {
                 name,
                 iface_type: ::VirtualInterfaceType::Tap,
+                netns: self.netns.clone(),
             }));
             Ok(::Virtualnterface {
                 queues: files
@@ -206,6 +248,20 @@ mod normal_linux {
             iface_type: ::VirtualInterfaceType,
             is_async: bool,
             queues: usize,
+        ) -> Result<(Vec<File>, String), TunTapError> {
+            match self.netns {
+                Some(ref netns) => netns
+                    .run(|| self.create_in_current_netns(name, iface_type, is_async, queues))?,
+                None => self.create_in_current_netns(name, iface_type, is_async, queues),
+            }
+        }
+
+        fn create_in_current_netns(
+            &self,
+            name: Option<&str>,
+            iface_type: ::VirtualInterfaceType,
+            is_async: bool,
+            queues: usize,
         ) -> Result<(Vec<File>, String), TunTapError> {
             if let Some(ref s) = name {
                 if s.is_empty() {
@@ -221,6 +277,12 @@ mod normal_linux {
                 });
             }
 
+            if let (::VirtualInterfaceType::Tun, Some(_)) = (iface_type, self.hw_addr) {
+                return Err(TunTapError::BadArguments {
+                    msg: "tun has no hardware address".to_owned(),
+                });
+            }
+
             let mut flags = TunTapFlags::IFF_NO_PI;
             flags.insert(match iface_type {
                 ::VirtualInterfaceType::Tun => TunTapFlags::IFF_TUN,
@@ -242,6 +304,14 @@ mod normal_linux {
                 }
             }
 
+            let mut iface = Iface::find_by_name(&resulting_name)?;
+            if let Some(mtu) = self.mtu {
+                iface.set_mtu(mtu)?;
+            }
+            if let Some(hw_addr) = self.hw_addr {
+                iface.set_hw_addr(hw_addr)?;
+            }
+
             Iface::up_by_name(&resulting_name)?;
 
             Ok((files, resulting_name))
@@ -251,3 +321,33 @@ mod normal_linux {
 
 #[cfg(target_os = "linux")]
 pub use self::normal_linux::*;
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use ifcontrol::Iface;
+
+    #[test]
+    fn test_tun_in_netns() {
+        let tun = Native::new()
+            .with_netns(NetNs::new().expect("create netns"))
+            .create_tun(Some("ifctltun0"), 1)
+            .expect("create tun in netns");
+        assert!(Iface::find_by_name("ifctltun0").is_err());
+
+        let info = tun.info().unwrap();
+        let netns = info.netns.expect("netns in info");
+        let found = netns
+            .run(|| Iface::find_by_name("ifctltun0").is_ok())
+            .unwrap();
+        assert!(found);
+
+        tun.rename("ifctltun1").expect("rename tun in netns");
+        assert_eq!(tun.info().unwrap().name, "ifctltun1");
+        assert!(Iface::find_by_name("ifctltun1").is_err());
+        let found = netns
+            .run(|| Iface::find_by_name("ifctltun1").is_ok())
+            .unwrap();
+        assert!(found);
+    }
+}
@@ -4,6 +4,7 @@
 #[macro_use]
 extern crate bitflags;
 extern crate bytes;
+extern crate eui48;
 #[macro_use]
 extern crate futures;
 extern crate parking_lot;
@@ -135,6 +136,23 @@ where
 pub struct VirtualInterfaceInfo {
     pub name: String,
     pub iface_type: VirtualInterfaceType,
+    /// Namespace, the interface was created in
+    #[cfg(target_os = "linux")]
+    pub netns: Option<Arc<ifcontrol::NetNs>>,
+}
+
+impl VirtualInterfaceInfo {
+    /// Runs `f` inside of the namespace of the interface
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+    fn run<T, F: FnOnce() -> Result<T, TunTapError>>(&self, f: F) -> Result<T, TunTapError> {
+        #[cfg(target_os = "linux")]
+        {
+            if let Some(ref netns) = self.netns {
+                return netns.run(f)?;
+            }
+        }
+        f()
+    }
 }
 
 pub struct Descriptor<C: DescriptorCloser> {
@@ -282,4 +300,16 @@ impl<D> Virtualnterface<D> {
     pub fn info(&self) -> Option<VirtualInterfaceInfo> {
         self.info.upgrade().map(|l| (*l.lock()).clone())
     }
+
+    /// Renames the interface and updates its `info()`
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+    pub fn rename(&self, new_name: &str) -> Result<(), TunTapError> {
+        let info = self.info.upgrade().ok_or_else(|| TunTapError::NotFound {
+            msg: "interface is already closed".to_owned(),
+        })?;
+        let mut info = info.lock();
+        info.run(|| Ok(ifcontrol::Iface::find_by_name(&info.name)?.rename(new_name)?))?;
+        info.name = new_name.to_owned();
+        Ok(())
+    }
 }